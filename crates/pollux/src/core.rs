@@ -2,5 +2,8 @@
 // SPDX-License-Identifier: MIT
 
 pub mod analysers;
+pub mod baselines;
+pub mod graphs;
 pub mod interfaces;
 pub mod models;
+pub mod policies;
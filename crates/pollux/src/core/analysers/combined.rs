@@ -1,62 +1,206 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::analysers::standalone::{
-    BuildReproducibilityChecker, CachedDataChecker, CrateTrustedPublishingChecker,
+use crate::core::analysers::standalone::{CachedDataChecker, VeracityFactorChecker};
+use crate::core::interfaces::{AnalyzedDataStorage, CrateVeracityAnalysis, NamedFactorCheck};
+use crate::core::models::{
+    CargoPackage, CrateVeracityChecks, FactorCheck, FactorEvidence, FactorStatus, VeracityFactor,
 };
-use crate::core::interfaces::{AnalyzedDataStorage, CrateVeracityAnalysis, VeracityFactorCheck};
-use crate::core::models::{CargoPackage, CrateVeracityChecks};
+use futures::StreamExt;
+use futures::stream;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 pub struct VeracityChecksAnalyser {
     cache: CachedDataChecker,
-    trusted_publishing: CrateTrustedPublishingChecker,
-    reproducibility: BuildReproducibilityChecker,
+    checkers: Vec<VeracityFactorChecker>,
+    rebuild_parallelism: usize,
+    prefetched_rebuilds: Mutex<HashMap<CargoPackage, FactorCheck<FactorEvidence>>>,
 }
 
 impl VeracityChecksAnalyser {
-    pub fn new(
-        cache: CachedDataChecker,
-        trusted_publishing: CrateTrustedPublishingChecker,
-        reproducibility: BuildReproducibilityChecker,
-    ) -> Self {
+    pub fn new(cache: CachedDataChecker, checkers: Vec<VeracityFactorChecker>, rebuild_parallelism: usize) -> Self {
         Self {
             cache,
-            trusted_publishing,
-            reproducibility,
+            checkers,
+            rebuild_parallelism: rebuild_parallelism.max(1),
+            prefetched_rebuilds: Mutex::new(HashMap::new()),
         }
     }
 
+    pub fn factors(&self) -> Vec<VeracityFactor> {
+        self.checkers.iter().map(|checker| checker.factor()).collect()
+    }
+
+    // oss-rebuild attestations live on a static bucket without rate limits, hence rebuild checks
+    // for a whole package set can run concurrently ahead of the rate-limited crates.io ones
+    pub async fn prefetch_rebuilds(&self, cargo_packages: &[CargoPackage]) {
+        let Some(checker) = self
+            .checkers
+            .iter()
+            .find(|checker| checker.factor() == VeracityFactor::ReproducibleBuilds)
+        else {
+            return;
+        };
+
+        let no_checks = &CrateVeracityChecks::default();
+        let pending = cargo_packages
+            .iter()
+            .filter(|cargo_package| self.requires_rebuild_check(cargo_package));
+
+        let prefetched = stream::iter(pending)
+            .map(|cargo_package| async move {
+                let check = checker.execute(cargo_package, no_checks).await;
+                (cargo_package, check)
+            })
+            .buffer_unordered(self.rebuild_parallelism)
+            .collect::<Vec<_>>()
+            .await;
+
+        let Ok(mut prefetched_rebuilds) = self.prefetched_rebuilds.lock() else {
+            return;
+        };
+
+        for (cargo_package, check) in prefetched {
+            match check {
+                Ok(check) => {
+                    prefetched_rebuilds.insert(cargo_package.clone(), check);
+                },
+                // Failed lookups get retried once the package is analysed
+                Err(e) => log::warn!("[pollux.core] cannot prefetch rebuild for {} : {}", cargo_package, e),
+            }
+        }
+    }
+
+    // Runs a single factor check, reusing cached or prefetched outcomes whenever possible
+    pub async fn execute_factor(
+        &self,
+        factor: VeracityFactor,
+        crate_info: &CargoPackage,
+    ) -> anyhow::Result<Option<FactorCheck<FactorEvidence>>> {
+        let Some(checker) = self.checkers.iter().find(|checker| checker.factor() == factor) else {
+            return Ok(None);
+        };
+
+        if let Some(cached_checks) = self.cache.retrieve(crate_info)?
+            && let Some(cached) = cached_checks.get(factor)
+            && !self.should_recheck(factor, cached)
+        {
+            return Ok(Some(cached.clone()));
+        }
+
+        let check = self
+            .execute_checker(checker, crate_info, &CrateVeracityChecks::default())
+            .await?;
+
+        Ok(Some(check))
+    }
+
+    fn requires_rebuild_check(&self, cargo_package: &CargoPackage) -> bool {
+        let Ok(Some(cached_checks)) = self.cache.retrieve(cargo_package) else {
+            return true;
+        };
+
+        cached_checks
+            .get(VeracityFactor::ReproducibleBuilds)
+            .is_none_or(|existing| self.should_recheck(VeracityFactor::ReproducibleBuilds, existing))
+    }
+
+    async fn execute_checker(
+        &self,
+        checker: &VeracityFactorChecker,
+        crate_info: &CargoPackage,
+        collected: &CrateVeracityChecks,
+    ) -> anyhow::Result<FactorCheck<FactorEvidence>> {
+        if checker.factor() == VeracityFactor::ReproducibleBuilds
+            && let Some(prefetched) = self
+                .prefetched_rebuilds
+                .lock()
+                .ok()
+                .and_then(|mut prefetched_rebuilds| prefetched_rebuilds.remove(crate_info))
+        {
+            return Ok(prefetched);
+        }
+
+        checker.execute(crate_info, collected).await
+    }
+
     async fn analyse(&self, crate_info: &CargoPackage) -> anyhow::Result<CrateVeracityChecks> {
-        let reproducible_build_evidence = self.reproducibility.execute(crate_info).await?;
-        let trusted_publishing_evidence = self.trusted_publishing.execute(crate_info).await?;
+        let mut checks = CrateVeracityChecks::default();
+
+        // Checkers run in the configured order, so later factors can rely on earlier ones
+        for checker in &self.checkers {
+            let check = self.execute_checker(checker, crate_info, &checks).await?;
+            checks.record(checker.factor(), check);
+        }
 
-        let checks = CrateVeracityChecks::new(trusted_publishing_evidence, reproducible_build_evidence);
         self.cache.save(crate_info, checks.clone())?;
         Ok(checks)
     }
-}
 
-impl CrateVeracityAnalysis for VeracityChecksAnalyser {
-    async fn execute(&self, cargo_package: &CargoPackage) -> anyhow::Result<CrateVeracityChecks> {
-        let Some(cached_checks) = self.cache.retrieve(cargo_package)? else {
-            return self.analyse(cargo_package).await;
+    async fn refresh(
+        &self,
+        cargo_package: &CargoPackage,
+        cached_checks: CrateVeracityChecks,
+    ) -> anyhow::Result<CrateVeracityChecks> {
+        let mut updated_checks = CrateVeracityChecks::default();
+
+        for checker in &self.checkers {
+            let factor = checker.factor();
+
+            let check = match cached_checks.get(factor) {
+                Some(existing) if !self.should_recheck(factor, existing) => existing.clone(),
+                _ => {
+                    log::info!("[pollux.core] will re-check {} for package {}", factor, cargo_package);
+                    self.execute_checker(checker, cargo_package, &updated_checks).await?
+                },
+            };
+
+            updated_checks.record(factor, check);
+        }
+
+        Ok(updated_checks)
+    }
+
+    // oss-rebuild keeps expanding its coverage, hence missing rebuilds are worth checking again
+    fn should_recheck(&self, factor: VeracityFactor, cached_check: &FactorCheck<FactorEvidence>) -> bool {
+        factor == VeracityFactor::ReproducibleBuilds && !cached_check.is_verified()
+    }
+
+    // An attestation only vouches for the exact artifact it describes, hence the digest
+    // of the attested subject must match the checksum of the artifact we actually consume
+    fn cross_check_rebuild_digest(&self, cargo_package: &CargoPackage, checks: &mut CrateVeracityChecks) {
+        let Some(checksum) = &cargo_package.checksum else {
+            return;
+        };
+
+        let Some(reproducibility) = checks.factors.get_mut(&VeracityFactor::ReproducibleBuilds) else {
+            return;
         };
 
-        let updated_reproducibility = match &cached_checks.reproducibility_evidence {
-            None => {
-                log::info!(
-                    "[pollux.core] will re-check reproducibility for package {}",
-                    cargo_package
-                );
-                self.reproducibility.execute(cargo_package).await?
-            },
-            Some(existing) => Some(existing.clone()),
+        let Some(FactorEvidence::Reproducibility(evidence)) = &reproducibility.evidence else {
+            return;
         };
 
-        let updated_checks =
-            CrateVeracityChecks::new(cached_checks.trusted_publishing_evidence, updated_reproducibility);
+        if reproducibility.is_verified() && !evidence.attests_checksum(checksum) {
+            log::warn!(
+                "[pollux.core] attested digest does not match checksum for package {}",
+                cargo_package
+            );
+            reproducibility.status = FactorStatus::Mismatch;
+        }
+    }
+}
+
+impl CrateVeracityAnalysis for VeracityChecksAnalyser {
+    async fn execute(&self, cargo_package: &CargoPackage) -> anyhow::Result<CrateVeracityChecks> {
+        let mut checks = match self.cache.retrieve(cargo_package)? {
+            None => self.analyse(cargo_package).await?,
+            Some(cached_checks) => self.refresh(cargo_package, cached_checks).await?,
+        };
 
-        Ok(updated_checks)
+        self.cross_check_rebuild_digest(cargo_package, &mut checks);
+        Ok(checks)
     }
 }
 
@@ -64,15 +208,21 @@ impl CrateVeracityAnalysis for VeracityChecksAnalyser {
 mod tests {
     use crate::core::analysers::combined::VeracityChecksAnalyser;
     use crate::core::analysers::standalone::{
-        BuildReproducibilityChecker, CachedDataChecker, CrateTrustedPublishingChecker, FakeVeracityChecker,
+        BuildReproducibilityChecker, CachedDataChecker, CrateRepositoryConsistencyChecker,
+        CrateTrustedPublishingChecker, CrateVcsProvenanceChecker, FakeVeracityChecker, VeracityFactorChecker,
     };
     use crate::core::interfaces::CrateVeracityAnalysis;
-    use crate::core::models::{CargoPackage, CrateVeracityChecks};
-    use assertor::EqualityAssertion;
+    use crate::core::models::{
+        AttestedSubject, CargoPackage, CrateVeracityChecks, FactorCheck, FactorEvidence, FactorStatus,
+        PublishingProvider, RepositoryConsistencyEvidence, ReproducibilityEvidence, TrustedPublishingEvidence,
+        VcsProvenanceEvidence, VeracityFactor,
+    };
+    use assertor::{BooleanAssertion, EqualityAssertion, OptionAssertion};
     use std::collections::HashMap;
-    use std::str::FromStr;
     use url::Url;
 
+    static REBUILD_PARALLELISM: usize = 4;
+
     struct CrateScenario {
         name: &'static str,
         version: &'static str,
@@ -88,44 +238,87 @@ mod tests {
         }
     }
 
+    fn convert_to_evidence(evidence: Option<&'static str>) -> Option<ReproducibilityEvidence> {
+        convert_to_url(evidence).map(ReproducibilityEvidence::new)
+    }
+
+    fn convert_to_publishing(evidence: Option<&'static str>) -> Option<TrustedPublishingEvidence> {
+        convert_to_url(evidence).map(|workflow_run| TrustedPublishingEvidence {
+            provider: PublishingProvider::GitHub,
+            repository: "fake-org/fake-crate".to_string(),
+            workflow_run,
+            commit_sha: "bbd8b099ea52bf4de18051d012c8113cf0dca23a".to_string(),
+        })
+    }
+
+    fn convert_to_check<E>(evidence: Option<E>) -> FactorCheck<E> {
+        match evidence {
+            None => FactorCheck::missing(),
+            Some(existing) => FactorCheck::verified(existing),
+        }
+    }
+
+    fn convert_to_checks(
+        trusted_publishing_evidence: Option<&'static str>,
+        reproducibility_evidence: Option<&'static str>,
+    ) -> CrateVeracityChecks {
+        let trusted_publishing = convert_to_check(convert_to_publishing(trusted_publishing_evidence));
+        let reproducibility = convert_to_check(convert_to_evidence(reproducibility_evidence));
+
+        CrateVeracityChecks::from([
+            (
+                VeracityFactor::TrustedPublishing,
+                trusted_publishing.map(FactorEvidence::TrustedPublishing),
+            ),
+            (
+                VeracityFactor::ReproducibleBuilds,
+                reproducibility.map(FactorEvidence::Reproducibility),
+            ),
+        ])
+    }
+
     fn fake_results_storage(scenario: &CrateScenario) -> HashMap<String, CrateVeracityChecks> {
         if scenario.serving_from_cache {
             let cache_key = CargoPackage::with(scenario.name, scenario.version).to_string();
-            let checks = CrateVeracityChecks::new(
-                convert_to_url(scenario.trusted_publishing_evidence),
-                convert_to_url(scenario.reproducibility_evidence),
-            );
+            let checks = convert_to_checks(scenario.trusted_publishing_evidence, scenario.reproducibility_evidence);
             HashMap::from([(cache_key, checks)])
         } else {
             HashMap::new()
         }
     }
 
-    fn fake_trusted_publishing_checker(scenario: &CrateScenario) -> FakeVeracityChecker {
-        let Some(evidence) = scenario.trusted_publishing_evidence else {
+    fn fake_trusted_publishing_checker(scenario: &CrateScenario) -> FakeVeracityChecker<TrustedPublishingEvidence> {
+        let Some(evidence) = convert_to_publishing(scenario.trusted_publishing_evidence) else {
             return FakeVeracityChecker(HashMap::new());
         };
 
         let package = CargoPackage::with(scenario.name, scenario.version);
 
-        FakeVeracityChecker(HashMap::from([(package, evidence.to_string())]))
+        FakeVeracityChecker::verifying(HashMap::from([(package, evidence)]))
     }
 
-    fn fake_reproducibility_checker(scenario: &CrateScenario) -> FakeVeracityChecker {
-        let Some(evidence) = scenario.reproducibility_evidence else {
+    fn fake_reproducibility_checker(scenario: &CrateScenario) -> FakeVeracityChecker<ReproducibilityEvidence> {
+        let Some(evidence) = convert_to_evidence(scenario.reproducibility_evidence) else {
             return FakeVeracityChecker(HashMap::new());
         };
 
         let package = CargoPackage::with(scenario.name, scenario.version);
 
-        FakeVeracityChecker(HashMap::from([(package, evidence.to_string())]))
+        FakeVeracityChecker::verifying(HashMap::from([(package, evidence)]))
     }
 
     fn crate_analyser(scenario: &CrateScenario) -> VeracityChecksAnalyser {
         VeracityChecksAnalyser::new(
             CachedDataChecker::FakeCache(fake_results_storage(scenario)),
-            CrateTrustedPublishingChecker::FakeRegistry(fake_trusted_publishing_checker(scenario)),
-            BuildReproducibilityChecker::FakeRebuilder(fake_reproducibility_checker(scenario)),
+            vec![
+                VeracityFactorChecker::TrustedPublishing(CrateTrustedPublishingChecker::FakeRegistry(
+                    fake_trusted_publishing_checker(scenario),
+                )),
+                VeracityFactorChecker::ReproducibleBuilds(BuildReproducibilityChecker::FakeRebuilder(
+                    fake_reproducibility_checker(scenario),
+                )),
+            ],
+            REBUILD_PARALLELISM,
         )
     }
 
@@ -150,7 +343,7 @@ mod tests {
 
         let analysed = analyser.execute(&cargo_package).await.unwrap();
 
-        let expected = CrateVeracityChecks::new(Url::from_str(gha_run_url).ok(), Url::from_str(ossrebuild_url).ok());
+        let expected = convert_to_checks(Some(gha_run_url), Some(ossrebuild_url));
 
         assertor::assert_that!(analysed).is_equal_to(expected);
     }
@@ -175,7 +368,7 @@ mod tests {
 
         let analysed = analyser.execute(&cargo_package).await.unwrap();
 
-        let expected = CrateVeracityChecks::new(None, Url::from_str(ossrebuild_url).ok());
+        let expected = convert_to_checks(None, Some(ossrebuild_url));
 
         assertor::assert_that!(analysed).is_equal_to(expected);
     }
@@ -200,8 +393,220 @@ mod tests {
 
         let analysed = analyser.execute(&cargo_package).await.unwrap();
 
-        let expected = CrateVeracityChecks::new(Url::from_str(gha_run_url).ok(), None);
+        let expected = convert_to_checks(Some(gha_run_url), None);
 
         assertor::assert_that!(analysed).is_equal_to(expected);
     }
+
+    fn rebuild_digest_analyser(cargo_package: &CargoPackage, attested_digest: &str) -> VeracityChecksAnalyser {
+        let ossrebuild_url = "https://shortn.ed/fake-crate-1.2.3.crate/rebuild.intoto.jsonl";
+        let mut evidence = convert_to_evidence(Some(ossrebuild_url)).unwrap();
+        evidence.subjects.push(AttestedSubject {
+            name: "fake-crate-1.2.3.crate".to_string(),
+            sha256: Some(attested_digest.to_string()),
+        });
+
+        VeracityChecksAnalyser::new(
+            CachedDataChecker::FakeCache(HashMap::new()),
+            vec![VeracityFactorChecker::ReproducibleBuilds(
+                BuildReproducibilityChecker::FakeRebuilder(FakeVeracityChecker::verifying(HashMap::from([(
+                    cargo_package.clone(),
+                    evidence,
+                )]))),
+            )],
+            REBUILD_PARALLELISM,
+        )
+    }
+
+    #[tokio::test]
+    async fn should_confirm_reproducibility_when_attested_digest_matches_checksum() {
+        let checksum = "dde20b3d026af13f561bdd0f15edf01fc734f0dafcedbaf42bba506a9517f223";
+        let cargo_package = CargoPackage::with_checksum(
+            "fake-crate".to_string(),
+            "1.2.3".to_string(),
+            Some(checksum.to_string()),
+        );
+
+        let analyser = rebuild_digest_analyser(&cargo_package, checksum);
+
+        let analysed = analyser.execute(&cargo_package).await.unwrap();
+
+        assertor::assert_that!(analysed.is_verified(VeracityFactor::ReproducibleBuilds)).is_true();
+    }
+
+    #[tokio::test]
+    async fn should_flag_mismatch_when_attested_digest_differs_from_checksum() {
+        let checksum = "dde20b3d026af13f561bdd0f15edf01fc734f0dafcedbaf42bba506a9517f223";
+        let attested_digest = "ace50bade8e6234aa140d9a2f552bbee1db4d353f69b8217bc503490fc1a9f26";
+        let cargo_package = CargoPackage::with_checksum(
+            "fake-crate".to_string(),
+            "1.2.3".to_string(),
+            Some(checksum.to_string()),
+        );
+
+        let analyser = rebuild_digest_analyser(&cargo_package, attested_digest);
+
+        let analysed = analyser.execute(&cargo_package).await.unwrap();
+
+        assertor::assert_that!(
+            analysed
+                .get(VeracityFactor::ReproducibleBuilds)
+                .map(|check| check.status)
+        )
+        .is_equal_to(Some(FactorStatus::Mismatch));
+        assertor::assert_that!(analysed.to_string()).is_equal_to("rebuild digest mismatch".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_flag_repository_mismatch_when_inspecting_sources() {
+        let gha_run_url = "https://shortn.ed/actions/runs/12345789";
+        let cargo_package = CargoPackage::with("fake-crate", "1.2.3");
+        let publishing = convert_to_publishing(Some(gha_run_url)).unwrap();
+
+        let repository_mismatch = FactorCheck::mismatch(RepositoryConsistencyEvidence {
+            declared_repository: Some("https://github.com/someone-else/fake-crate".to_string()),
+            publishing_repository: publishing.repository_url(),
+            path_in_vcs: None,
+        });
+
+        let analyser = VeracityChecksAnalyser::new(
+            CachedDataChecker::FakeCache(HashMap::new()),
+            vec![
+                VeracityFactorChecker::TrustedPublishing(CrateTrustedPublishingChecker::FakeRegistry(
+                    FakeVeracityChecker::verifying(HashMap::from([(cargo_package.clone(), publishing)])),
+                )),
+                VeracityFactorChecker::RepositoryConsistency(CrateRepositoryConsistencyChecker::FakeSources(
+                    FakeVeracityChecker(HashMap::from([(cargo_package.clone(), repository_mismatch)])),
+                )),
+            ],
+            REBUILD_PARALLELISM,
+        );
+
+        let analysed = analyser.execute(&cargo_package).await.unwrap();
+
+        assertor::assert_that!(
+            analysed
+                .get(VeracityFactor::RepositoryConsistency)
+                .map(|check| check.status)
+        )
+        .is_equal_to(Some(FactorStatus::Mismatch));
+        assertor::assert_that!(analysed.to_string()).is_equal_to("trusted publishing; repository mismatch".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_flag_vcs_provenance_mismatch_when_inspecting_sources() {
+        let gha_run_url = "https://shortn.ed/actions/runs/12345789";
+        let cargo_package = CargoPackage::with("fake-crate", "1.2.3");
+        let publishing = convert_to_publishing(Some(gha_run_url)).unwrap();
+
+        let vcs_mismatch = FactorCheck::mismatch(VcsProvenanceEvidence {
+            commit_sha: "0a1b2c3d4e5f60718293a4b5c6d7e8f901234567".to_string(),
+            path_in_vcs: None,
+            dirty: false,
+            publishing_commit_sha: Some(publishing.commit_sha.clone()),
+        });
+
+        let analyser = VeracityChecksAnalyser::new(
+            CachedDataChecker::FakeCache(HashMap::new()),
+            vec![
+                VeracityFactorChecker::TrustedPublishing(CrateTrustedPublishingChecker::FakeRegistry(
+                    FakeVeracityChecker::verifying(HashMap::from([(cargo_package.clone(), publishing)])),
+                )),
+                VeracityFactorChecker::VcsProvenance(CrateVcsProvenanceChecker::FakeSources(FakeVeracityChecker(
+                    HashMap::from([(cargo_package.clone(), vcs_mismatch)]),
+                ))),
+            ],
+            REBUILD_PARALLELISM,
+        );
+
+        let analysed = analyser.execute(&cargo_package).await.unwrap();
+
+        assertor::assert_that!(analysed.get(VeracityFactor::VcsProvenance).map(|check| check.status))
+            .is_equal_to(Some(FactorStatus::Mismatch));
+        assertor::assert_that!(analysed.to_string())
+            .is_equal_to("trusted publishing; vcs provenance mismatch".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_skip_repository_consistency_when_not_inspecting_sources() {
+        let scenario = CrateScenario {
+            name: "fake-crate",
+            version: "1.2.3",
+            trusted_publishing_evidence: Some("https://shortn.ed/actions/runs/12345789"),
+            reproducibility_evidence: None,
+            serving_from_cache: false,
+        };
+
+        let cargo_package = CargoPackage::with(scenario.name, scenario.version);
+
+        let analyser = crate_analyser(&scenario);
+
+        let analysed = analyser.execute(&cargo_package).await.unwrap();
+
+        assertor::assert_that!(analysed.get(VeracityFactor::RepositoryConsistency)).is_none();
+        assertor::assert_that!(analysed.get(VeracityFactor::VcsProvenance)).is_none();
+    }
+
+    #[tokio::test]
+    async fn should_reuse_prefetched_rebuilds_when_analysing() {
+        let ossrebuild_url = "https://shortn.ed/fake-crate-1.2.3.crate/rebuild.intoto.jsonl";
+
+        let scenario = CrateScenario {
+            name: "fake-crate",
+            version: "1.2.3",
+            trusted_publishing_evidence: None,
+            reproducibility_evidence: Some(ossrebuild_url),
+            serving_from_cache: false,
+        };
+
+        let cargo_package = CargoPackage::with(scenario.name, scenario.version);
+        let other_package = CargoPackage::with("other-crate", "0.1.0");
+
+        let analyser = crate_analyser(&scenario);
+        analyser
+            .prefetch_rebuilds(&[cargo_package.clone(), other_package.clone()])
+            .await;
+
+        let prefetched = analyser.prefetched_rebuilds.lock().unwrap().len();
+        let analysed = analyser.execute(&cargo_package).await.unwrap();
+        let remaining = analyser.prefetched_rebuilds.lock().unwrap().len();
+
+        assertor::assert_that!(prefetched).is_equal_to(2);
+        assertor::assert_that!(remaining).is_equal_to(1);
+        assertor::assert_that!(analysed.is_verified(VeracityFactor::ReproducibleBuilds)).is_true();
+    }
+
+    #[tokio::test]
+    async fn should_check_single_factor_reusing_prefetched_rebuilds() {
+        let ossrebuild_url = "https://shortn.ed/fake-crate-1.2.3.crate/rebuild.intoto.jsonl";
+
+        let scenario = CrateScenario {
+            name: "fake-crate",
+            version: "1.2.3",
+            trusted_publishing_evidence: None,
+            reproducibility_evidence: Some(ossrebuild_url),
+            serving_from_cache: false,
+        };
+
+        let cargo_package = CargoPackage::with(scenario.name, scenario.version);
+
+        let analyser = crate_analyser(&scenario);
+        analyser.prefetch_rebuilds(std::slice::from_ref(&cargo_package)).await;
+
+        let reproducibility = analyser
+            .execute_factor(VeracityFactor::ReproducibleBuilds, &cargo_package)
+            .await
+            .unwrap();
+
+        let not_configured = analyser
+            .execute_factor(VeracityFactor::VcsProvenance, &cargo_package)
+            .await
+            .unwrap();
+
+        let remaining = analyser.prefetched_rebuilds.lock().unwrap().len();
+
+        assertor::assert_that!(remaining).is_equal_to(0);
+        assertor::assert_that!(reproducibility.is_some_and(|check| check.is_verified())).is_true();
+        assertor::assert_that!(not_configured).is_none();
+    }
 }
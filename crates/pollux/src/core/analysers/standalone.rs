@@ -1,25 +1,31 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::interfaces::{AnalyzedDataStorage, VeracityFactorCheck};
-use crate::core::models::{CargoPackage, CrateVeracityChecks};
+use crate::core::interfaces::{AnalyzedDataStorage, NamedFactorCheck, VeracityFactorCheck};
+use crate::core::models::{
+    CargoPackage, CrateVeracityChecks, FactorCheck, FactorEvidence, ReproducibilityEvidence, VeracityFactor,
+};
 use crate::infra::caching::analysis::AnalysedPackagesCache;
 use crate::infra::networking::crates::OfficialCratesRegistryChecker;
+use crate::infra::networking::crates::provenance::{RepositoryConsistencyChecker, VcsProvenanceChecker};
 use crate::infra::networking::ossrebuild::OssRebuildChecker;
-use url::Url;
 
 pub enum CrateTrustedPublishingChecker {
     CratesOfficialRegistry(OfficialCratesRegistryChecker),
     #[cfg(test)]
-    FakeRegistry(FakeVeracityChecker),
+    FakeRegistry(FakeVeracityChecker<TrustedPublishingEvidence>),
 }
 
 impl VeracityFactorCheck for CrateTrustedPublishingChecker {
-    async fn execute(&self, crate_info: &CargoPackage) -> anyhow::Result<Option<Url>> {
+    type Evidence = FactorEvidence;
+
+    async fn execute(&self, crate_info: &CargoPackage) -> anyhow::Result<FactorCheck<FactorEvidence>> {
         match self {
             CrateTrustedPublishingChecker::CratesOfficialRegistry(delegate) => delegate.execute(crate_info).await,
             #[cfg(test)]
-            CrateTrustedPublishingChecker::FakeRegistry(fake) => fake.execute(crate_info).await,
+            CrateTrustedPublishingChecker::FakeRegistry(fake) => {
+                Ok(fake.execute(crate_info).await?.map(FactorEvidence::TrustedPublishing))
+            },
         }
     }
 }
@@ -27,11 +33,13 @@ impl VeracityFactorCheck for CrateTrustedPublishingChecker {
 pub enum BuildReproducibilityChecker {
     GoogleOssRebuild(OssRebuildChecker),
     #[cfg(test)]
-    FakeRebuilder(FakeVeracityChecker),
+    FakeRebuilder(FakeVeracityChecker<ReproducibilityEvidence>),
 }
 
 impl VeracityFactorCheck for BuildReproducibilityChecker {
-    async fn execute(&self, crate_info: &CargoPackage) -> anyhow::Result<Option<Url>> {
+    type Evidence = ReproducibilityEvidence;
+
+    async fn execute(&self, crate_info: &CargoPackage) -> anyhow::Result<FactorCheck<ReproducibilityEvidence>> {
         match self {
             BuildReproducibilityChecker::GoogleOssRebuild(delegate) => delegate.execute(crate_info).await,
             #[cfg(test)]
@@ -40,6 +48,93 @@ impl VeracityFactorCheck for BuildReproducibilityChecker {
     }
 }
 
+pub enum CrateRepositoryConsistencyChecker {
+    CrateSources(RepositoryConsistencyChecker),
+    #[cfg(test)]
+    FakeSources(FakeVeracityChecker<RepositoryConsistencyEvidence>),
+}
+
+impl NamedFactorCheck for CrateRepositoryConsistencyChecker {
+    fn factor(&self) -> VeracityFactor {
+        VeracityFactor::RepositoryConsistency
+    }
+
+    async fn execute(
+        &self,
+        crate_info: &CargoPackage,
+        collected: &CrateVeracityChecks,
+    ) -> anyhow::Result<FactorCheck<FactorEvidence>> {
+        match self {
+            CrateRepositoryConsistencyChecker::CrateSources(delegate) => delegate.execute(crate_info, collected).await,
+            #[cfg(test)]
+            CrateRepositoryConsistencyChecker::FakeSources(fake) => Ok(fake
+                .execute(crate_info)
+                .await?
+                .map(FactorEvidence::RepositoryConsistency)),
+        }
+    }
+}
+
+pub enum CrateVcsProvenanceChecker {
+    CrateSources(VcsProvenanceChecker),
+    #[cfg(test)]
+    FakeSources(FakeVeracityChecker<VcsProvenanceEvidence>),
+}
+
+impl NamedFactorCheck for CrateVcsProvenanceChecker {
+    fn factor(&self) -> VeracityFactor {
+        VeracityFactor::VcsProvenance
+    }
+
+    async fn execute(
+        &self,
+        crate_info: &CargoPackage,
+        collected: &CrateVeracityChecks,
+    ) -> anyhow::Result<FactorCheck<FactorEvidence>> {
+        match self {
+            CrateVcsProvenanceChecker::CrateSources(delegate) => delegate.execute(crate_info, collected).await,
+            #[cfg(test)]
+            CrateVcsProvenanceChecker::FakeSources(fake) => {
+                Ok(fake.execute(crate_info).await?.map(FactorEvidence::VcsProvenance))
+            },
+        }
+    }
+}
+
+pub enum VeracityFactorChecker {
+    TrustedPublishing(CrateTrustedPublishingChecker),
+    ReproducibleBuilds(BuildReproducibilityChecker),
+    RepositoryConsistency(CrateRepositoryConsistencyChecker),
+    VcsProvenance(CrateVcsProvenanceChecker),
+}
+
+impl NamedFactorCheck for VeracityFactorChecker {
+    fn factor(&self) -> VeracityFactor {
+        match self {
+            VeracityFactorChecker::TrustedPublishing(_) => VeracityFactor::TrustedPublishing,
+            VeracityFactorChecker::ReproducibleBuilds(_) => VeracityFactor::ReproducibleBuilds,
+            VeracityFactorChecker::RepositoryConsistency(delegate) => delegate.factor(),
+            VeracityFactorChecker::VcsProvenance(delegate) => delegate.factor(),
+        }
+    }
+
+    async fn execute(
+        &self,
+        crate_info: &CargoPackage,
+        collected: &CrateVeracityChecks,
+    ) -> anyhow::Result<FactorCheck<FactorEvidence>> {
+        let check = match self {
+            VeracityFactorChecker::TrustedPublishing(delegate) => delegate.execute(crate_info).await?,
+            VeracityFactorChecker::ReproducibleBuilds(delegate) => {
+                delegate.execute(crate_info).await?.map(FactorEvidence::Reproducibility)
+            },
+            VeracityFactorChecker::RepositoryConsistency(delegate) => delegate.execute(crate_info, collected).await?,
+            VeracityFactorChecker::VcsProvenance(delegate) => delegate.execute(crate_info, collected).await?,
+        };
+        Ok(check)
+    }
+}
+
 pub enum CachedDataChecker {
     FileSystem(AnalysedPackagesCache),
     #[cfg(test)]
@@ -67,18 +162,32 @@ impl AnalyzedDataStorage for CachedDataChecker {
     }
 }
 
+#[cfg(test)]
+use crate::core::models::{RepositoryConsistencyEvidence, TrustedPublishingEvidence, VcsProvenanceEvidence};
 #[cfg(test)]
 use std::collections::HashMap;
+
 #[cfg(test)]
-use std::str::FromStr;
+pub struct FakeVeracityChecker<E>(pub HashMap<CargoPackage, FactorCheck<E>>);
 
 #[cfg(test)]
-pub struct FakeVeracityChecker(pub HashMap<CargoPackage, String>);
+impl<E> FakeVeracityChecker<E> {
+    pub fn verifying(evidences: HashMap<CargoPackage, E>) -> Self {
+        let checks = evidences
+            .into_iter()
+            .map(|(cargo_package, evidence)| (cargo_package, FactorCheck::verified(evidence)))
+            .collect();
+
+        Self(checks)
+    }
+}
 
 #[cfg(test)]
-impl VeracityFactorCheck for FakeVeracityChecker {
-    async fn execute(&self, cargo_package: &CargoPackage) -> anyhow::Result<Option<Url>> {
-        let url = self.0.get(cargo_package).map(|url| Url::from_str(url).unwrap());
-        Ok(url)
+impl<E: Clone> VeracityFactorCheck for FakeVeracityChecker<E> {
+    type Evidence = E;
+
+    async fn execute(&self, cargo_package: &CargoPackage) -> anyhow::Result<FactorCheck<E>> {
+        let check = self.0.get(cargo_package).cloned().unwrap_or(FactorCheck::missing());
+        Ok(check)
     }
 }
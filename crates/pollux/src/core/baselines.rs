@@ -0,0 +1,380 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::{AnalysisResults, CargoPackage, FactorStatus, UnverifiablePackage, VeracityFactor};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub name: String,
+    pub version: String,
+    pub factors: BTreeMap<VeracityFactor, FactorStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UnverifiableBaselineEntry {
+    pub name: String,
+    pub version: String,
+    pub source: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub packages: Vec<BaselineEntry>,
+    #[serde(default)]
+    pub unverifiable: Vec<UnverifiableBaselineEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BaselineRegression {
+    NewPackage {
+        cargo_package: CargoPackage,
+        unmet_factors: Vec<VeracityFactor>,
+    },
+    WorsenedFactor {
+        cargo_package: CargoPackage,
+        factor: VeracityFactor,
+        previous: FactorStatus,
+        current: FactorStatus,
+    },
+    NewUnverifiable {
+        unverifiable: UnverifiablePackage,
+    },
+}
+
+impl Display for BaselineRegression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineRegression::NewPackage {
+                cargo_package,
+                unmet_factors,
+            } => {
+                let factors = unmet_factors
+                    .iter()
+                    .map(|factor| factor.to_string())
+                    .collect::<Vec<_>>();
+                write!(f, "{} : new package without {}", cargo_package, factors.join(", "))
+            },
+            BaselineRegression::WorsenedFactor {
+                cargo_package,
+                factor,
+                previous,
+                current,
+            } => write!(
+                f,
+                "{} : {} went from {} to {}",
+                cargo_package, factor, previous, current
+            ),
+            BaselineRegression::NewUnverifiable { unverifiable } => write!(
+                f,
+                "{} : new package from unverifiable source {}",
+                unverifiable.cargo_package, unverifiable.source
+            ),
+        }
+    }
+}
+
+// Packages whose analysis failed (e.g. on network hiccups) tell nothing about trust,
+// hence they are reported apart from regressions
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BaselineComparison {
+    pub regressions: Vec<BaselineRegression>,
+    pub not_analysed: Vec<CargoPackage>,
+}
+
+impl Baseline {
+    pub fn from_results(results: &AnalysisResults) -> Self {
+        let packages = results
+            .outcomes
+            .iter()
+            .map(|(cargo_package, checks)| BaselineEntry {
+                name: cargo_package.name.clone(),
+                version: cargo_package.version.clone(),
+                factors: checks
+                    .iter()
+                    .flat_map(|existing| existing.factors.iter())
+                    .map(|(factor, check)| (*factor, check.status))
+                    .collect(),
+            })
+            .collect();
+
+        let unverifiable = results
+            .unverifiable
+            .iter()
+            .map(|unverifiable| UnverifiableBaselineEntry {
+                name: unverifiable.cargo_package.name.clone(),
+                version: unverifiable.cargo_package.version.clone(),
+                source: unverifiable.source.to_string(),
+            })
+            .collect();
+
+        Self { packages, unverifiable }
+    }
+
+    pub fn compare(&self, results: &AnalysisResults) -> BaselineComparison {
+        let mut regressions = vec![];
+        let mut not_analysed = vec![];
+
+        for (cargo_package, checks) in &results.outcomes {
+            let Some(existing) = checks else {
+                not_analysed.push(cargo_package.clone());
+                continue;
+            };
+
+            let Some(baseline_entry) = self.find_entry(cargo_package) else {
+                let unmet_factors = existing
+                    .factors
+                    .iter()
+                    .filter(|(_, check)| !check.is_verified())
+                    .map(|(factor, _)| *factor)
+                    .collect::<Vec<_>>();
+
+                if !unmet_factors.is_empty() {
+                    regressions.push(BaselineRegression::NewPackage {
+                        cargo_package: cargo_package.clone(),
+                        unmet_factors,
+                    });
+                }
+
+                continue;
+            };
+
+            for (factor, check) in &existing.factors {
+                let Some(previous) = baseline_entry.factors.get(factor) else {
+                    continue;
+                };
+
+                if trust_rank(check.status) < trust_rank(*previous) {
+                    regressions.push(BaselineRegression::WorsenedFactor {
+                        cargo_package: cargo_package.clone(),
+                        factor: *factor,
+                        previous: *previous,
+                        current: check.status,
+                    });
+                }
+            }
+        }
+
+        // Crates already pulled from git, paths or other registries may change versions,
+        // but any newcomer from those sources brings in code without any trust evidence
+        for unverifiable in &results.unverifiable {
+            let baselined = self
+                .unverifiable
+                .iter()
+                .any(|entry| entry.name == unverifiable.cargo_package.name);
+
+            if !baselined {
+                regressions.push(BaselineRegression::NewUnverifiable {
+                    unverifiable: unverifiable.clone(),
+                });
+            }
+        }
+
+        BaselineComparison {
+            regressions,
+            not_analysed,
+        }
+    }
+
+    // Version bumps are compared against the closest baselined version of the same crate,
+    // hence only crates never seen before count as new packages
+    fn find_entry(&self, cargo_package: &CargoPackage) -> Option<&BaselineEntry> {
+        let same_crate = self
+            .packages
+            .iter()
+            .filter(|entry| entry.name == cargo_package.name)
+            .collect::<Vec<_>>();
+
+        if let Some(exact) = same_crate.iter().find(|entry| entry.version == cargo_package.version) {
+            return Some(exact);
+        }
+
+        let Ok(current) = Version::parse(&cargo_package.version) else {
+            return same_crate.first().copied();
+        };
+
+        let versioned = same_crate
+            .iter()
+            .filter_map(|entry| Version::parse(&entry.version).ok().map(|version| (version, *entry)))
+            .collect::<Vec<_>>();
+
+        let preceding = versioned
+            .iter()
+            .filter(|(version, _)| *version < current)
+            .max_by(|first, second| first.0.cmp(&second.0));
+
+        let following = versioned
+            .iter()
+            .filter(|(version, _)| *version > current)
+            .min_by(|first, second| first.0.cmp(&second.0));
+
+        preceding
+            .or(following)
+            .map(|(_, entry)| *entry)
+            .or_else(|| same_crate.first().copied())
+    }
+}
+
+fn trust_rank(status: FactorStatus) -> u8 {
+    match status {
+        FactorStatus::Mismatch => 0,
+        FactorStatus::Missing => 1,
+        FactorStatus::Verified => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::baselines::{Baseline, BaselineRegression};
+    use crate::core::models::{
+        AnalysisOutcome, AnalysisResults, CargoPackage, CrateVeracityChecks, FactorCheck, FactorStatus, PackageSource,
+        StatisticsForPackages, UnverifiablePackage, VeracityFactor,
+    };
+    use assertor::EqualityAssertion;
+    use std::collections::{BTreeMap, HashMap};
+
+    fn analysed(name: &str, version: &str, trusted_publishing: FactorStatus) -> AnalysisOutcome {
+        let check = FactorCheck {
+            status: trusted_publishing,
+            evidence: None,
+        };
+
+        let checks = CrateVeracityChecks::from([(VeracityFactor::TrustedPublishing, check)]);
+        (CargoPackage::with(name, version), Some(checks))
+    }
+
+    fn analysis_results(outcomes: Vec<AnalysisOutcome>) -> AnalysisResults {
+        AnalysisResults {
+            statistics: StatisticsForPackages {
+                total: outcomes.len(),
+                unverifiable: 0,
+                verified: BTreeMap::new(),
+                mismatches: BTreeMap::new(),
+                publishers: BTreeMap::new(),
+                tiers: BTreeMap::new(),
+            },
+            outcomes,
+            unverifiable: vec![],
+            depths: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn should_not_report_regressions_against_own_baseline() {
+        let results = analysis_results(vec![
+            analysed("tokio", "1.47.0", FactorStatus::Verified),
+            analysed("openssl-sys", "0.9.109", FactorStatus::Missing),
+        ]);
+
+        let baseline = Baseline::from_results(&results);
+
+        assertor::assert_that!(baseline.compare(&results).regressions).is_equal_to(vec![]);
+    }
+
+    #[test]
+    fn should_report_new_packages_and_worsened_factors() {
+        let previous = analysis_results(vec![
+            analysed("tokio", "1.47.0", FactorStatus::Verified),
+            analysed("openssl-sys", "0.9.109", FactorStatus::Missing),
+        ]);
+
+        let current = analysis_results(vec![
+            analysed("tokio", "1.48.0", FactorStatus::Missing),
+            analysed("openssl-sys", "0.9.110", FactorStatus::Missing),
+            analysed("libc", "0.2.175", FactorStatus::Missing),
+            analysed("serde", "1.0.228", FactorStatus::Verified),
+        ]);
+
+        let regressions = Baseline::from_results(&previous).compare(&current).regressions;
+
+        let expected = vec![
+            BaselineRegression::WorsenedFactor {
+                cargo_package: CargoPackage::with("tokio", "1.48.0"),
+                factor: VeracityFactor::TrustedPublishing,
+                previous: FactorStatus::Verified,
+                current: FactorStatus::Missing,
+            },
+            BaselineRegression::NewPackage {
+                cargo_package: CargoPackage::with("libc", "0.2.175"),
+                unmet_factors: vec![VeracityFactor::TrustedPublishing],
+            },
+        ];
+
+        assertor::assert_that!(regressions).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_compare_version_bumps_against_closest_baselined_version() {
+        let previous = analysis_results(vec![
+            analysed("syn", "1.0.109", FactorStatus::Missing),
+            analysed("syn", "2.0.100", FactorStatus::Verified),
+            analysed("openssl-sys", "0.9.109", FactorStatus::Missing),
+        ]);
+
+        let current = analysis_results(vec![
+            analysed("syn", "1.0.110", FactorStatus::Missing),
+            analysed("syn", "2.0.106", FactorStatus::Missing),
+            analysed("openssl-sys", "0.10.0", FactorStatus::Missing),
+        ]);
+
+        let regressions = Baseline::from_results(&previous).compare(&current).regressions;
+
+        let expected = vec![BaselineRegression::WorsenedFactor {
+            cargo_package: CargoPackage::with("syn", "2.0.106"),
+            factor: VeracityFactor::TrustedPublishing,
+            previous: FactorStatus::Verified,
+            current: FactorStatus::Missing,
+        }];
+
+        assertor::assert_that!(regressions).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_report_failed_analyses_apart_from_regressions() {
+        let previous = analysis_results(vec![
+            analysed("tokio", "1.47.0", FactorStatus::Verified),
+            analysed("openssl-sys", "0.9.109", FactorStatus::Missing),
+        ]);
+
+        let mut current = analysis_results(vec![analysed("openssl-sys", "0.9.109", FactorStatus::Missing)]);
+        current.outcomes.push((CargoPackage::with("tokio", "1.47.0"), None));
+
+        let comparison = Baseline::from_results(&previous).compare(&current);
+
+        assertor::assert_that!(comparison.regressions).is_equal_to(vec![]);
+        assertor::assert_that!(comparison.not_analysed).is_equal_to(vec![CargoPackage::with("tokio", "1.47.0")]);
+    }
+
+    #[test]
+    fn should_report_new_packages_from_unverifiable_sources() {
+        let from_git = |name: &str, version: &str, revision: &str| UnverifiablePackage {
+            cargo_package: CargoPackage::with(name, version),
+            source: PackageSource::Git {
+                url: format!("https://github.com/acme/{}", name),
+                revision: Some(revision.to_string()),
+            },
+        };
+
+        let mut previous = analysis_results(vec![]);
+        previous.unverifiable = vec![from_git("acme-utils", "0.1.0", "a1b2c3")];
+
+        let mut current = analysis_results(vec![]);
+        current.unverifiable = vec![
+            from_git("acme-utils", "0.2.0", "d4e5f6"),
+            from_git("patched-tokio", "1.48.0", "0f1e2d"),
+        ];
+
+        let baseline = Baseline::from_results(&previous);
+        let regressions = baseline.compare(&current).regressions;
+
+        let expected = vec![BaselineRegression::NewUnverifiable {
+            unverifiable: from_git("patched-tokio", "1.48.0", "0f1e2d"),
+        }];
+
+        assertor::assert_that!(baseline.unverifiable.len()).is_equal_to(1);
+        assertor::assert_that!(regressions).is_equal_to(expected);
+    }
+}
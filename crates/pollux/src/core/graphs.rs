@@ -0,0 +1,369 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::CargoPackage;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyRoute {
+    pub shortest_path: Vec<CargoPackage>,
+    pub total_paths: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    local_packages: Vec<CargoPackage>,
+    dependencies: HashMap<CargoPackage, Vec<CargoPackage>>,
+}
+
+impl DependencyGraph {
+    pub fn new(local_packages: Vec<CargoPackage>, dependencies: HashMap<CargoPackage, Vec<CargoPackage>>) -> Self {
+        Self {
+            local_packages,
+            dependencies,
+        }
+    }
+
+    pub fn is_local(&self, cargo_package: &CargoPackage) -> bool {
+        self.local_packages.contains(cargo_package)
+    }
+
+    pub fn local_packages(&self) -> &[CargoPackage] {
+        &self.local_packages
+    }
+
+    pub fn depths(&self) -> HashMap<CargoPackage, usize> {
+        self.depths_from(&self.local_packages)
+    }
+
+    // Breadth-first walk from the given roots, so each reachable package gets its shortest depth
+    pub fn depths_from(&self, roots: &[CargoPackage]) -> HashMap<CargoPackage, usize> {
+        let mut depths = HashMap::new();
+        let mut to_visit = VecDeque::new();
+
+        for root in roots {
+            depths.insert(root.clone(), 0);
+            to_visit.push_back(root.clone());
+        }
+
+        while let Some(current) = to_visit.pop_front() {
+            let next_depth = depths[&current] + 1;
+
+            for dependency in self.dependencies_of(&current) {
+                if !depths.contains_key(dependency) {
+                    depths.insert(dependency.clone(), next_depth);
+                    to_visit.push_back(dependency.clone());
+                }
+            }
+        }
+
+        depths
+    }
+
+    // Enumerating every simple path blows up on diamond-heavy graphs, so paths are collapsed
+    // by the direct dependency they enter through, keeping the shortest one along with a count
+    pub fn routes_to(&self, crate_name: &str, crate_version: Option<&str>) -> Vec<DependencyRoute> {
+        let is_target = |cargo_package: &CargoPackage| {
+            cargo_package.name == crate_name && crate_version.is_none_or(|version| version == cargo_package.version)
+        };
+
+        // Only packages able to reach the target are worth walking through
+        let reaching_target = self.reaching(is_target);
+        let mut counted_paths = HashMap::new();
+        let mut routes = vec![];
+
+        for (entry_point, mut shortest_path) in self.entry_points(&reaching_target, &is_target) {
+            shortest_path.extend(self.shortest_path_from(entry_point, &reaching_target, &is_target));
+            let total_paths = self.count_paths(
+                entry_point,
+                &reaching_target,
+                &is_target,
+                &mut counted_paths,
+                &mut HashSet::new(),
+            );
+
+            routes.push(DependencyRoute {
+                shortest_path,
+                total_paths,
+            });
+        }
+
+        routes
+    }
+
+    // Local packages not pulled by other local packages, usually workspace members
+    fn roots(&self) -> Vec<&CargoPackage> {
+        let pulled_by_locals = self
+            .local_packages
+            .iter()
+            .flat_map(|local| self.dependencies_of(local))
+            .collect::<HashSet<_>>();
+
+        let roots = self
+            .local_packages
+            .iter()
+            .filter(|local| !pulled_by_locals.contains(local))
+            .collect::<Vec<_>>();
+
+        match roots.is_empty() {
+            true => self.local_packages.iter().collect(),
+            false => roots,
+        }
+    }
+
+    fn reaching(&self, is_target: impl Fn(&CargoPackage) -> bool) -> HashSet<&CargoPackage> {
+        let mut dependents = HashMap::<&CargoPackage, Vec<&CargoPackage>>::new();
+
+        for (dependent, dependencies) in &self.dependencies {
+            for dependency in dependencies {
+                dependents.entry(dependency).or_default().push(dependent);
+            }
+        }
+
+        let mut reaching = self
+            .dependencies
+            .keys()
+            .filter(|cargo_package| is_target(cargo_package))
+            .collect::<HashSet<_>>();
+
+        let mut to_visit = reaching.iter().copied().collect::<VecDeque<_>>();
+
+        while let Some(current) = to_visit.pop_front() {
+            for dependent in dependents.get(current).into_iter().flatten() {
+                if reaching.insert(dependent) {
+                    to_visit.push_back(dependent);
+                }
+            }
+        }
+
+        reaching
+    }
+
+    // Breadth-first walk over local packages, so each direct dependency comes with the shortest local path into it
+    fn entry_points<'a>(
+        &'a self,
+        reaching_target: &HashSet<&'a CargoPackage>,
+        is_target: &impl Fn(&CargoPackage) -> bool,
+    ) -> Vec<(&'a CargoPackage, Vec<CargoPackage>)> {
+        let mut local_paths = HashMap::<&CargoPackage, Vec<CargoPackage>>::new();
+        let mut to_visit = VecDeque::new();
+        let mut entry_points = vec![];
+
+        for root in self.roots() {
+            if is_target(root) {
+                entry_points.push((root, vec![]));
+            } else if reaching_target.contains(root) {
+                local_paths.insert(root, vec![root.clone()]);
+                to_visit.push_back(root);
+            }
+        }
+
+        while let Some(current) = to_visit.pop_front() {
+            let current_path = local_paths[current].clone();
+
+            for dependency in self.dependencies_of(current) {
+                if !reaching_target.contains(dependency) {
+                    continue;
+                }
+
+                if self.is_local(dependency) && !is_target(dependency) {
+                    // Dev-dependencies may introduce cycles between workspace members
+                    if !local_paths.contains_key(dependency) {
+                        let mut dependency_path = current_path.clone();
+                        dependency_path.push(dependency.clone());
+                        local_paths.insert(dependency, dependency_path);
+                        to_visit.push_back(dependency);
+                    }
+                } else if entry_points.iter().all(|(entry_point, _)| *entry_point != dependency) {
+                    entry_points.push((dependency, current_path.clone()));
+                }
+            }
+        }
+
+        entry_points
+    }
+
+    fn shortest_path_from(
+        &self,
+        entry_point: &CargoPackage,
+        reaching_target: &HashSet<&CargoPackage>,
+        is_target: &impl Fn(&CargoPackage) -> bool,
+    ) -> Vec<CargoPackage> {
+        let mut previous_hops = HashMap::<&CargoPackage, &CargoPackage>::new();
+        let mut to_visit = VecDeque::from([entry_point]);
+        let mut found = None;
+
+        while let Some(current) = to_visit.pop_front() {
+            if is_target(current) {
+                found = Some(current);
+                break;
+            }
+
+            for dependency in self.dependencies_of(current) {
+                if reaching_target.contains(dependency)
+                    && dependency != entry_point
+                    && !previous_hops.contains_key(dependency)
+                {
+                    previous_hops.insert(dependency, current);
+                    to_visit.push_back(dependency);
+                }
+            }
+        }
+
+        let mut shortest_path = vec![];
+        let mut current = found;
+
+        while let Some(hop) = current {
+            shortest_path.push(hop.clone());
+            current = previous_hops.get(hop).copied();
+        }
+
+        shortest_path.reverse();
+        shortest_path
+    }
+
+    // Registry packages never depend on each other in cycles, so counts can be memoised per package
+    fn count_paths<'a>(
+        &'a self,
+        current: &'a CargoPackage,
+        reaching_target: &HashSet<&'a CargoPackage>,
+        is_target: &impl Fn(&CargoPackage) -> bool,
+        counted_paths: &mut HashMap<&'a CargoPackage, u64>,
+        visiting: &mut HashSet<&'a CargoPackage>,
+    ) -> u64 {
+        if is_target(current) {
+            return 1;
+        }
+
+        if let Some(counted) = counted_paths.get(current) {
+            return *counted;
+        }
+
+        visiting.insert(current);
+
+        let mut total_paths = 0u64;
+
+        for dependency in self.dependencies_of(current) {
+            if reaching_target.contains(dependency) && !visiting.contains(dependency) {
+                let paths = self.count_paths(dependency, reaching_target, is_target, counted_paths, visiting);
+                total_paths = total_paths.saturating_add(paths);
+            }
+        }
+
+        visiting.remove(current);
+        counted_paths.insert(current, total_paths);
+        total_paths
+    }
+
+    fn dependencies_of(&self, cargo_package: &CargoPackage) -> impl Iterator<Item = &CargoPackage> {
+        self.dependencies.get(cargo_package).into_iter().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::graphs::{DependencyGraph, DependencyRoute};
+    use crate::core::models::CargoPackage;
+    use assertor::EqualityAssertion;
+    use std::collections::HashMap;
+
+    fn fake_graph() -> DependencyGraph {
+        let project = CargoPackage::with("my-project", "1.0.0");
+        let helper = CargoPackage::with("my-helper", "1.0.0");
+        let tokio = CargoPackage::with("tokio", "1.47.1");
+        let mio = CargoPackage::with("mio", "1.0.4");
+        let libc = CargoPackage::with("libc", "0.2.175");
+
+        let dependencies = HashMap::from([
+            (project.clone(), vec![helper.clone(), tokio.clone()]),
+            (helper.clone(), vec![libc.clone()]),
+            (tokio.clone(), vec![mio.clone(), libc.clone()]),
+            (mio.clone(), vec![libc.clone()]),
+            (libc, vec![]),
+        ]);
+
+        DependencyGraph::new(vec![project, helper], dependencies)
+    }
+
+    #[test]
+    fn should_compute_shortest_depths() {
+        let depths = fake_graph().depths();
+
+        assertor::assert_that!(depths[&CargoPackage::with("my-helper", "1.0.0")]).is_equal_to(0);
+        assertor::assert_that!(depths[&CargoPackage::with("tokio", "1.47.1")]).is_equal_to(1);
+        assertor::assert_that!(depths[&CargoPackage::with("libc", "0.2.175")]).is_equal_to(1);
+        assertor::assert_that!(depths[&CargoPackage::with("mio", "1.0.4")]).is_equal_to(2);
+    }
+
+    #[test]
+    fn should_compute_depths_within_member_closure() {
+        let helper = CargoPackage::with("my-helper", "1.0.0");
+        let depths = fake_graph().depths_from(&[helper]);
+
+        assertor::assert_that!(depths.len()).is_equal_to(2);
+        assertor::assert_that!(depths[&CargoPackage::with("libc", "0.2.175")]).is_equal_to(1);
+    }
+
+    fn hop_names(route: &DependencyRoute) -> String {
+        route
+            .shortest_path
+            .iter()
+            .map(|hop| hop.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+
+    #[test]
+    fn should_collapse_paths_by_direct_dependency() {
+        let routes = fake_graph().routes_to("libc", None);
+
+        let collapsed = routes
+            .iter()
+            .map(|route| (hop_names(route), route.total_paths))
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            ("my-project -> tokio -> libc".to_string(), 2),
+            ("my-project -> my-helper -> libc".to_string(), 1),
+        ];
+
+        assertor::assert_that!(collapsed).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_count_paths_across_diamond_heavy_graphs() {
+        let project = CargoPackage::with("my-project", "1.0.0");
+        let diamonds = 40;
+        let mut dependencies = HashMap::new();
+
+        let knots = (0..=diamonds)
+            .map(|index| CargoPackage::with(&format!("knot-{}", index), "1.0.0"))
+            .collect::<Vec<_>>();
+
+        dependencies.insert(project.clone(), vec![knots[0].clone()]);
+
+        for index in 0..diamonds {
+            let left = CargoPackage::with(&format!("left-{}", index), "1.0.0");
+            let right = CargoPackage::with(&format!("right-{}", index), "1.0.0");
+            dependencies.insert(knots[index].clone(), vec![left.clone(), right.clone()]);
+            dependencies.insert(left, vec![knots[index + 1].clone()]);
+            dependencies.insert(right, vec![knots[index + 1].clone()]);
+        }
+
+        dependencies.insert(knots[diamonds].clone(), vec![]);
+
+        let graph = DependencyGraph::new(vec![project], dependencies);
+        let routes = graph.routes_to(&format!("knot-{}", diamonds), None);
+
+        assertor::assert_that!(routes.len()).is_equal_to(1);
+        assertor::assert_that!(routes[0].total_paths).is_equal_to(1u64 << diamonds);
+        assertor::assert_that!(routes[0].shortest_path.len()).is_equal_to(2 * diamonds + 2);
+    }
+
+    #[test]
+    fn should_not_find_paths_for_other_versions() {
+        let routes = fake_graph().routes_to("libc", Some("0.2.174"));
+
+        assertor::assert_that!(routes.len()).is_equal_to(0);
+    }
+}
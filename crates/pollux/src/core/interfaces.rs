@@ -1,11 +1,22 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::{CargoPackage, CrateVeracityChecks};
-use url::Url;
+use crate::core::models::{CargoPackage, CrateVeracityChecks, FactorCheck, FactorEvidence, VeracityFactor};
 
 pub trait VeracityFactorCheck {
-    async fn execute(&self, cargo_package: &CargoPackage) -> anyhow::Result<Option<Url>>;
+    type Evidence;
+
+    async fn execute(&self, cargo_package: &CargoPackage) -> anyhow::Result<FactorCheck<Self::Evidence>>;
+}
+
+pub trait NamedFactorCheck {
+    fn factor(&self) -> VeracityFactor;
+
+    async fn execute(
+        &self,
+        cargo_package: &CargoPackage,
+        collected: &CrateVeracityChecks,
+    ) -> anyhow::Result<FactorCheck<FactorEvidence>>;
 }
 
 pub trait CrateVeracityAnalysis {
@@ -1,40 +1,131 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+use crate::core::graphs::DependencyGraph;
+use anyhow::{Context, bail};
 use clap::ValueEnum;
 use packageurl::PackageUrl;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
 use std::str::FromStr;
 use url::Url;
 
-#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize)]
+// Checksums are only known for some sources, hence packages are identified by name and version
+#[derive(Clone, Debug, Serialize)]
 pub struct CargoPackage {
     pub name: String,
     pub version: String,
+    pub checksum: Option<String>,
+}
+
+impl PartialEq for CargoPackage {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.version == other.version
+    }
+}
+
+impl Eq for CargoPackage {}
+
+impl Hash for CargoPackage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.version.hash(state);
+    }
 }
 
 impl TryFrom<String> for CargoPackage {
     type Error = anyhow::Error;
 
     fn try_from(value: String) -> anyhow::Result<Self> {
-        let purl = PackageUrl::from_str(value.as_str())?;
-        let name = purl.name();
-        let version = purl.version().expect("");
-        let cargo_package = CargoPackage::with(name, version);
-        Ok(cargo_package)
+        let crate_reference = CrateReference::parse(&value, VersionSelection::Default)?;
+
+        let RequestedVersion::Exact(version) = crate_reference.version else {
+            bail!("pollux.purl : missing version for {}", value);
+        };
+
+        Ok(CargoPackage::with(&crate_reference.name, &version))
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VersionSelection {
+    #[default]
+    Default,
+    LatestStable,
+    Latest,
+}
+
+impl Display for VersionSelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionSelection::Default => f.write_str("default version"),
+            VersionSelection::LatestStable => f.write_str("latest stable version"),
+            VersionSelection::Latest => f.write_str("latest version"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RequestedVersion {
+    Exact(String),
+    Selected(VersionSelection),
+}
+
+// A crate as requested by users, whose version may still need to be resolved against crates.io
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateReference {
+    pub name: String,
+    pub version: RequestedVersion,
+}
+
+impl CrateReference {
+    pub fn parse(raw_purl: &str, selection: VersionSelection) -> anyhow::Result<Self> {
+        let purl = PackageUrl::from_str(raw_purl)
+            .with_context(|| format!("pollux.purl : malformed package url ({})", raw_purl))?;
+
+        if purl.ty() != "cargo" {
+            bail!("pollux.purl : expecting a pkg:cargo package url, found {}", raw_purl);
+        }
+
+        let version = match purl.version() {
+            Some(version) => RequestedVersion::Exact(version.to_string()),
+            None => RequestedVersion::Selected(selection),
+        };
+
+        let crate_reference = CrateReference {
+            name: purl.name().to_string(),
+            version,
+        };
+
+        Ok(crate_reference)
     }
 }
 
 impl CargoPackage {
     pub fn new(name: String, version: String) -> Self {
-        Self { name, version }
+        Self {
+            name,
+            version,
+            checksum: None,
+        }
     }
 
     pub fn with(name: &str, version: &str) -> Self {
         Self {
             name: name.to_string(),
             version: version.to_string(),
+            checksum: None,
+        }
+    }
+
+    pub fn with_checksum(name: String, version: String, checksum: Option<String>) -> Self {
+        Self {
+            name,
+            version,
+            checksum,
         }
     }
 }
@@ -45,29 +136,358 @@ impl Display for CargoPackage {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PublishingProvider {
+    GitHub,
+    GitLab,
+}
+
+impl Display for PublishingProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PublishingProvider::GitHub => f.write_str("github"),
+            PublishingProvider::GitLab => f.write_str("gitlab"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct TrustedPublishingEvidence {
+    pub provider: PublishingProvider,
+    pub repository: String,
+    pub workflow_run: Url,
+    pub commit_sha: String,
+}
+
+impl TrustedPublishingEvidence {
+    pub fn repository_url(&self) -> String {
+        let host = match self.provider {
+            PublishingProvider::GitHub => "github.com",
+            PublishingProvider::GitLab => "gitlab.com",
+        };
+
+        format!("https://{}/{}", host, self.repository)
+    }
+
+    pub fn matches_repository(&self, declared_repository: &str, path_in_vcs: Option<&str>) -> bool {
+        let expected = normalize_repository(&self.repository_url());
+        let declared = normalize_repository(declared_repository);
+
+        if declared == expected {
+            return true;
+        }
+
+        // declared repositories may point to a subfolder, e.g. https://github.com/org/repo/tree/main/crate,
+        // which must then agree with the path cargo recorded when packaging the crate
+        let Some(subfolder) = declared.strip_prefix(&format!("{}/", expected)) else {
+            return false;
+        };
+
+        let packaged_path = path_in_vcs
+            .map(|path| path.trim_matches('/').to_lowercase())
+            .filter(|path| !path.is_empty());
+
+        match packaged_path {
+            Some(path) => subfolder == path || subfolder.ends_with(&format!("/{}", path)),
+            None => true,
+        }
+    }
+}
+
+fn normalize_repository(repository: &str) -> String {
+    let lowercased = repository.trim().to_lowercase();
+
+    let without_scheme = ["git+https://", "https://", "http://", "ssh://git@", "git://", "git@"]
+        .iter()
+        .find_map(|scheme| lowercased.strip_prefix(scheme))
+        .unwrap_or(&lowercased);
+
+    let without_host_prefix = without_scheme
+        .strip_prefix("www.")
+        .unwrap_or(without_scheme)
+        .replacen(':', "/", 1);
+
+    let without_slashes = without_host_prefix.trim_end_matches('/');
+    without_slashes
+        .strip_suffix(".git")
+        .unwrap_or(without_slashes)
+        .to_string()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq)]
+pub enum PublishMethod {
+    TrustedPublishing,
+    UserToken,
+}
+
+impl Display for PublishMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PublishMethod::TrustedPublishing => f.write_str("trusted publishing"),
+            PublishMethod::UserToken => f.write_str("user token"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CratesPublisher {
+    pub login: String,
+    pub name: Option<String>,
+}
+
+impl Display for CratesPublisher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} ({})", self.login, name),
+            None => f.write_str(&self.login),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct UserTokenPublishingEvidence {
+    pub published_by: CratesPublisher,
+}
+
+// Earlier release published with trusted publishing, while the checked one was not
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct PublishingDowngradeEvidence {
+    pub previous_version: String,
+    pub previous_publishing: TrustedPublishingEvidence,
+    pub published_by: Option<CratesPublisher>,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct RepositoryConsistencyEvidence {
+    pub declared_repository: Option<String>,
+    pub publishing_repository: String,
+    #[serde(default)]
+    pub path_in_vcs: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct VcsProvenanceEvidence {
+    pub commit_sha: String,
+    pub path_in_vcs: Option<String>,
+    pub dirty: bool,
+    pub publishing_commit_sha: Option<String>,
+}
+
+impl VcsProvenanceEvidence {
+    pub fn matches_publishing_commit(&self) -> Option<bool> {
+        self.publishing_commit_sha
+            .as_ref()
+            .map(|publishing| publishing.eq_ignore_ascii_case(&self.commit_sha))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct AttestedSubject {
+    pub name: String,
+    pub sha256: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct ReproducibilityEvidence {
+    pub attestation_url: Url,
+    pub build_strategy: Option<String>,
+    pub builder_id: Option<String>,
+    pub toolchain: Option<String>,
+    pub subjects: Vec<AttestedSubject>,
+}
+
+impl ReproducibilityEvidence {
+    pub fn new(attestation_url: Url) -> Self {
+        Self {
+            attestation_url,
+            build_strategy: None,
+            builder_id: None,
+            toolchain: None,
+            subjects: vec![],
+        }
+    }
+
+    pub fn attests_checksum(&self, checksum: &str) -> bool {
+        self.subjects
+            .iter()
+            .filter_map(|subject| subject.sha256.as_ref())
+            .any(|digest| digest.eq_ignore_ascii_case(checksum))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VeracityFactor {
+    TrustedPublishing,
+    ReproducibleBuilds,
+    RepositoryConsistency,
+    VcsProvenance,
+}
+
+impl VeracityFactor {
+    pub fn requires_sources(&self) -> bool {
+        matches!(
+            self,
+            VeracityFactor::RepositoryConsistency | VeracityFactor::VcsProvenance
+        )
+    }
+
+    pub fn label(&self, status: FactorStatus) -> Option<&'static str> {
+        match (self, status) {
+            (VeracityFactor::TrustedPublishing, FactorStatus::Verified) => Some("trusted publishing"),
+            (VeracityFactor::TrustedPublishing, FactorStatus::Mismatch) => Some("trusted publishing downgrade"),
+            (VeracityFactor::ReproducibleBuilds, FactorStatus::Verified) => Some("reproducible builds"),
+            (VeracityFactor::ReproducibleBuilds, FactorStatus::Mismatch) => Some("rebuild digest mismatch"),
+            (VeracityFactor::RepositoryConsistency, FactorStatus::Mismatch) => Some("repository mismatch"),
+            (VeracityFactor::VcsProvenance, FactorStatus::Verified) => Some("vcs provenance"),
+            (VeracityFactor::VcsProvenance, FactorStatus::Mismatch) => Some("vcs provenance mismatch"),
+            (_, _) => None,
+        }
+    }
+}
+
+impl Display for VeracityFactor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VeracityFactor::TrustedPublishing => f.write_str("trusted-publishing"),
+            VeracityFactor::ReproducibleBuilds => f.write_str("reproducible-builds"),
+            VeracityFactor::RepositoryConsistency => f.write_str("repository-consistency"),
+            VeracityFactor::VcsProvenance => f.write_str("vcs-provenance"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FactorStatus {
+    Verified,
+    Mismatch,
+    Missing,
+}
+
+impl Display for FactorStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FactorStatus::Verified => f.write_str("verified"),
+            FactorStatus::Mismatch => f.write_str("mismatch"),
+            FactorStatus::Missing => f.write_str("missing"),
+        }
+    }
+}
+
+// Untagged, since factor checks are already keyed by their VeracityFactor
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FactorEvidence {
+    TrustedPublishing(TrustedPublishingEvidence),
+    PublishingDowngrade(PublishingDowngradeEvidence),
+    Reproducibility(ReproducibilityEvidence),
+    RepositoryConsistency(RepositoryConsistencyEvidence),
+    VcsProvenance(VcsProvenanceEvidence),
+    // Kept last, since it's the most permissive shape to deserialize
+    UserTokenPublishing(UserTokenPublishingEvidence),
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct FactorCheck<E> {
+    pub status: FactorStatus,
+    pub evidence: Option<E>,
+}
+
+impl<E> FactorCheck<E> {
+    pub fn verified(evidence: E) -> Self {
+        Self {
+            status: FactorStatus::Verified,
+            evidence: Some(evidence),
+        }
+    }
+
+    pub fn mismatch(evidence: E) -> Self {
+        Self {
+            status: FactorStatus::Mismatch,
+            evidence: Some(evidence),
+        }
+    }
+
+    pub fn missing() -> Self {
+        Self {
+            status: FactorStatus::Missing,
+            evidence: None,
+        }
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.status == FactorStatus::Verified
+    }
+
+    pub fn is_mismatch(&self) -> bool {
+        self.status == FactorStatus::Mismatch
+    }
+
+    pub fn map<T>(self, convert: impl FnOnce(E) -> T) -> FactorCheck<T> {
+        FactorCheck {
+            status: self.status,
+            evidence: self.evidence.map(convert),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Hash, Eq, Serialize, Deserialize)]
 pub struct CrateVeracityChecks {
-    pub trusted_publishing_evidence: Option<Url>,
-    pub reproducibility_evidence: Option<Url>,
+    pub factors: BTreeMap<VeracityFactor, FactorCheck<FactorEvidence>>,
 }
 
 impl CrateVeracityChecks {
-    pub fn new(trusted_publishing_evidence: Option<Url>, reproducibility_evidence: Option<Url>) -> Self {
+    pub fn record(&mut self, factor: VeracityFactor, check: FactorCheck<FactorEvidence>) {
+        self.factors.insert(factor, check);
+    }
+
+    pub fn get(&self, factor: VeracityFactor) -> Option<&FactorCheck<FactorEvidence>> {
+        self.factors.get(&factor)
+    }
+
+    pub fn is_verified(&self, factor: VeracityFactor) -> bool {
+        self.get(factor).is_some_and(|check| check.is_verified())
+    }
+
+    pub fn trusted_publishing(&self) -> Option<&TrustedPublishingEvidence> {
+        match self.get(VeracityFactor::TrustedPublishing)?.evidence.as_ref()? {
+            FactorEvidence::TrustedPublishing(evidence) => Some(evidence),
+            _ => None,
+        }
+    }
+
+    pub fn publisher(&self) -> Option<&CratesPublisher> {
+        match self.get(VeracityFactor::TrustedPublishing)?.evidence.as_ref()? {
+            FactorEvidence::PublishingDowngrade(evidence) => evidence.published_by.as_ref(),
+            FactorEvidence::UserTokenPublishing(evidence) => Some(&evidence.published_by),
+            _ => None,
+        }
+    }
+}
+
+impl<const N: usize> From<[(VeracityFactor, FactorCheck<FactorEvidence>); N]> for CrateVeracityChecks {
+    fn from(checks: [(VeracityFactor, FactorCheck<FactorEvidence>); N]) -> Self {
         Self {
-            trusted_publishing_evidence,
-            reproducibility_evidence,
+            factors: BTreeMap::from(checks),
         }
     }
 }
 
 impl Display for CrateVeracityChecks {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match (&self.trusted_publishing_evidence, &self.reproducibility_evidence) {
-            (Some(_), Some(_)) => f.write_str("trusted publishing; reproducible builds"),
-            (Some(_), None) => f.write_str("trusted publishing"),
-            (None, Some(_)) => f.write_str("reproducible builds"),
-            (None, None) => f.write_str("none"),
+        let labels = self
+            .factors
+            .iter()
+            .filter_map(|(factor, check)| factor.label(check.status))
+            .collect::<Vec<_>>();
+
+        if labels.is_empty() {
+            return f.write_str("none");
         }
+
+        f.write_str(&labels.join("; "))
     }
 }
 
@@ -92,17 +512,186 @@ pub enum InquireCoverage {
     Huge,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DependencyKind {
+    Normal,
+    Build,
+    Dev,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MetadataFilters {
+    pub target: Option<String>,
+    pub features: Vec<String>,
+    pub all_features: bool,
+    pub no_default_features: bool,
+    pub kinds: Vec<DependencyKind>,
+}
+
+// See https://crates.io/data-access#database-dumps
+#[derive(Clone, Debug, PartialEq)]
+pub enum DatabaseDumpLocation {
+    Latest,
+    LocalFile(PathBuf),
+}
+
+// Listing of crate versions attested by oss-rebuild
+#[derive(Clone, Debug, PartialEq)]
+pub enum RebuildsSnapshotLocation {
+    Latest,
+    LocalFile(PathBuf),
+}
+
+// See https://crates.io/data-access#api
+// Bursts only spend tokens saved while idling, hence the sustained rate never goes above the cap
+pub static MAX_CRATESIO_REQUESTS_PER_SECOND: f64 = 1.0;
+pub static MAX_CRATESIO_BURST: u32 = 3;
+
+#[derive(Clone, Debug)]
+pub struct RateLimits {
+    pub requests_per_second: f64,
+    pub burst: u32,
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 0.9,
+            burst: 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DependencyTier {
+    Direct,
+    Transitive,
+}
+
+impl DependencyTier {
+    pub fn from_depth(depth: usize) -> Self {
+        match depth {
+            0 | 1 => DependencyTier::Direct,
+            _ => DependencyTier::Transitive,
+        }
+    }
+}
+
+impl Display for DependencyTier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DependencyTier::Direct => f.write_str("direct"),
+            DependencyTier::Transitive => f.write_str("transitive"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PackageSource {
+    Git { url: String, revision: Option<String> },
+    Path { path: String },
+    Registry { url: String },
+}
+
+impl Display for PackageSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackageSource::Git {
+                url,
+                revision: Some(revision),
+            } => write!(f, "git {}#{}", url, revision),
+            PackageSource::Git { url, revision: None } => write!(f, "git {}", url),
+            PackageSource::Path { path } => write!(f, "path {}", path),
+            PackageSource::Registry { url } => write!(f, "registry {}", url),
+        }
+    }
+}
+
+// Packages not served by crates.io, for which no trust evidence can be gathered
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnverifiablePackage {
+    pub cargo_package: CargoPackage,
+    pub source: PackageSource,
+}
+
+impl Display for UnverifiablePackage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.cargo_package, self.source)
+    }
+}
+
+pub struct ResolvedDependencies {
+    pub packages: Vec<CargoPackage>,
+    pub unverifiable: Vec<UnverifiablePackage>,
+    pub graph: DependencyGraph,
+}
+
 pub type AnalysisOutcome = (CargoPackage, Option<CrateVeracityChecks>);
 
 pub struct StatisticsForPackages {
     pub total: usize,
-    pub trusted_publishing: usize,
-    pub reproducible_builds: usize,
+    pub unverifiable: usize,
+    pub verified: BTreeMap<VeracityFactor, usize>,
+    pub mismatches: BTreeMap<VeracityFactor, usize>,
+    pub publishers: BTreeMap<CratesPublisher, usize>,
+    pub tiers: BTreeMap<DependencyTier, TierStatistics>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TierStatistics {
+    pub total: usize,
+    pub verified: BTreeMap<VeracityFactor, usize>,
 }
 
 pub struct AnalysisResults {
     pub statistics: StatisticsForPackages,
     pub outcomes: Vec<AnalysisOutcome>,
+    pub unverifiable: Vec<UnverifiablePackage>,
+    // Shortest distance from workspace roots, hence direct dependencies sit at depth 1
+    pub depths: HashMap<CargoPackage, usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublishedVersion {
+    pub cargo_package: CargoPackage,
+    pub published_at: Option<String>,
+    pub yanked: bool,
+    pub trusted_publishing: FactorCheck<FactorEvidence>,
+}
+
+pub type HistoryOutcome = (PublishedVersion, Option<CrateVeracityChecks>);
+
+pub struct MemberAnalysisResults {
+    pub member: CargoPackage,
+    pub results: AnalysisResults,
+}
+
+pub struct WorkspaceAnalysisResults {
+    pub members: Vec<MemberAnalysisResults>,
+    pub rollup: AnalysisResults,
+}
+
+impl AnalysisResults {
+    pub fn depth_of(&self, cargo_package: &CargoPackage) -> Option<usize> {
+        self.depths.get(cargo_package).copied()
+    }
+
+    pub fn tier_of(&self, cargo_package: &CargoPackage) -> Option<DependencyTier> {
+        self.depth_of(cargo_package).map(DependencyTier::from_depth)
+    }
+}
+
+pub struct DependencyHop {
+    pub cargo_package: CargoPackage,
+    pub local: bool,
+    pub unverifiable_source: Option<PackageSource>,
+    pub checks: Option<CrateVeracityChecks>,
+}
+
+// Shortest path entering through a direct dependency, standing for all others entering through it
+pub struct DependencyPath {
+    pub hops: Vec<DependencyHop>,
+    pub total_paths: u64,
 }
 
 #[derive(Serialize, Debug)]
@@ -120,3 +709,64 @@ pub struct EcosystemInquiringResults {
     pub presence_of_reproducibility: String,
     pub outcomes: Vec<InquiringOutcome>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{CargoPackage, CrateReference, DependencyTier, RequestedVersion, VersionSelection};
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use std::collections::HashSet;
+
+    #[test]
+    fn should_map_depths_to_dependency_tiers() {
+        // Depth zero stands for local packages, which are reported along with direct dependencies
+        let tiers = [0, 1, 2, 7].map(DependencyTier::from_depth);
+
+        let expected = [
+            DependencyTier::Direct,
+            DependencyTier::Direct,
+            DependencyTier::Transitive,
+            DependencyTier::Transitive,
+        ];
+
+        assertor::assert_that!(tiers).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_identify_packages_by_name_and_version_only() {
+        let checksum = Some("dde20b3d026af13f561bdd0f15edf01fc734f0dafcedbaf42bba506a9517f223".to_string());
+        let locked = CargoPackage::with_checksum("serde".to_string(), "1.0.228".to_string(), checksum);
+        let unlocked = CargoPackage::with("serde", "1.0.228");
+
+        let packages = HashSet::from([locked.clone(), unlocked.clone()]);
+
+        assertor::assert_that!(locked == unlocked).is_true();
+        assertor::assert_that!(packages.len()).is_equal_to(1);
+        assertor::assert_that!(locked == CargoPackage::with("serde", "1.0.227")).is_false();
+    }
+
+    #[test]
+    fn should_parse_versioned_purls() {
+        let cargo_package = CargoPackage::try_from("pkg:cargo/serde@1.0.228".to_string()).unwrap();
+
+        assertor::assert_that!(cargo_package).is_equal_to(CargoPackage::with("serde", "1.0.228"));
+    }
+
+    #[test]
+    fn should_defer_version_selection_for_versionless_purls() {
+        let crate_reference = CrateReference::parse("pkg:cargo/serde", VersionSelection::LatestStable).unwrap();
+
+        let expected = CrateReference {
+            name: "serde".to_string(),
+            version: RequestedVersion::Selected(VersionSelection::LatestStable),
+        };
+
+        assertor::assert_that!(crate_reference).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_reject_malformed_purls() {
+        assertor::assert_that!(CargoPackage::try_from("serde@1.0.228".to_string()).is_err()).is_true();
+        assertor::assert_that!(CargoPackage::try_from("pkg:npm/left-pad@1.3.0".to_string()).is_err()).is_true();
+        assertor::assert_that!(CargoPackage::try_from("pkg:cargo/serde".to_string()).is_err()).is_true();
+    }
+}
@@ -0,0 +1,471 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::{AnalysisResults, CargoPackage, DependencyTier, FactorStatus, PackageSource, VeracityFactor};
+use jiff::civil::Date;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PolicyScope {
+    #[default]
+    All,
+    Direct,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyRequirement {
+    pub factor: VeracityFactor,
+    #[serde(default)]
+    pub scope: PolicyScope,
+    #[serde(default = "any_crate")]
+    pub crates: String,
+}
+
+fn any_crate() -> String {
+    "*".to_string()
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyThreshold {
+    pub factor: VeracityFactor,
+    pub minimum_percentage: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyExemption {
+    #[serde(rename = "crate")]
+    pub crate_name: String,
+    pub version: Option<String>,
+    pub factor: Option<VeracityFactor>,
+    pub reason: String,
+    pub expires: Date,
+}
+
+impl PolicyExemption {
+    fn covers(&self, cargo_package: &CargoPackage, factor: VeracityFactor) -> bool {
+        self.crate_name == cargo_package.name
+            && self
+                .version
+                .as_ref()
+                .is_none_or(|version| *version == cargo_package.version)
+            && self.factor.is_none_or(|exempted| exempted == factor)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TrustPolicy {
+    #[serde(default)]
+    pub requirements: Vec<PolicyRequirement>,
+    #[serde(default)]
+    pub thresholds: Vec<PolicyThreshold>,
+    #[serde(default)]
+    pub exemptions: Vec<PolicyExemption>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PolicyViolation {
+    UnmetRequirement {
+        cargo_package: CargoPackage,
+        factor: VeracityFactor,
+        status: Option<FactorStatus>,
+    },
+    UnverifiableSource {
+        cargo_package: CargoPackage,
+        factor: VeracityFactor,
+        source: PackageSource,
+    },
+    UnmetThreshold {
+        factor: VeracityFactor,
+        minimum_percentage: usize,
+        actual_percentage: usize,
+    },
+    ExpiredExemption {
+        crate_name: String,
+        reason: String,
+        expires: Date,
+    },
+}
+
+impl Display for PolicyViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicyViolation::UnmetRequirement {
+                cargo_package,
+                factor,
+                status,
+            } => {
+                let outcome = match status {
+                    Some(existing) => existing.to_string(),
+                    None => "not analysed".to_string(),
+                };
+                write!(f, "{} : {} required, found {}", cargo_package, factor, outcome)
+            },
+            PolicyViolation::UnverifiableSource {
+                cargo_package,
+                factor,
+                source,
+            } => write!(
+                f,
+                "{} : {} required, found unverifiable source ({})",
+                cargo_package, factor, source
+            ),
+            PolicyViolation::UnmetThreshold {
+                factor,
+                minimum_percentage,
+                actual_percentage,
+            } => write!(
+                f,
+                "{} : at least {}% of packages required, found {}%",
+                factor, minimum_percentage, actual_percentage
+            ),
+            PolicyViolation::ExpiredExemption {
+                crate_name,
+                reason,
+                expires,
+            } => write!(f, "exemption for {} expired at {} ({})", crate_name, expires, reason),
+        }
+    }
+}
+
+impl TrustPolicy {
+    pub fn factors(&self) -> Vec<VeracityFactor> {
+        let required = self.requirements.iter().map(|requirement| requirement.factor);
+        let thresholds = self.thresholds.iter().map(|threshold| threshold.factor);
+        required.chain(thresholds).collect()
+    }
+
+    pub fn evaluate(&self, results: &AnalysisResults, today: Date) -> Vec<PolicyViolation> {
+        let active_exemptions = self
+            .exemptions
+            .iter()
+            .filter(|exemption| exemption.expires >= today)
+            .collect::<Vec<_>>();
+
+        let mut violations = self
+            .exemptions
+            .iter()
+            .filter(|exemption| exemption.expires < today)
+            .map(|exemption| PolicyViolation::ExpiredExemption {
+                crate_name: exemption.crate_name.clone(),
+                reason: exemption.reason.clone(),
+                expires: exemption.expires,
+            })
+            .collect::<Vec<_>>();
+
+        for (cargo_package, checks) in &results.outcomes {
+            for requirement in &self.requirements {
+                if !self.applies_to(requirement, cargo_package, results) {
+                    continue;
+                }
+
+                let status = checks
+                    .as_ref()
+                    .and_then(|existing| existing.get(requirement.factor))
+                    .map(|check| check.status);
+
+                let exempted = active_exemptions
+                    .iter()
+                    .any(|exemption| exemption.covers(cargo_package, requirement.factor));
+
+                if status == Some(FactorStatus::Verified) || exempted {
+                    continue;
+                }
+
+                violations.push(PolicyViolation::UnmetRequirement {
+                    cargo_package: cargo_package.clone(),
+                    factor: requirement.factor,
+                    status,
+                });
+            }
+        }
+
+        // Packages outside crates.io can't meet any requirement, unless explicitly exempted
+        for unverifiable_package in &results.unverifiable {
+            let cargo_package = &unverifiable_package.cargo_package;
+
+            for requirement in &self.requirements {
+                if !self.applies_to(requirement, cargo_package, results) {
+                    continue;
+                }
+
+                let exempted = active_exemptions
+                    .iter()
+                    .any(|exemption| exemption.covers(cargo_package, requirement.factor));
+
+                if exempted {
+                    continue;
+                }
+
+                violations.push(PolicyViolation::UnverifiableSource {
+                    cargo_package: cargo_package.clone(),
+                    factor: requirement.factor,
+                    source: unverifiable_package.source.clone(),
+                });
+            }
+        }
+
+        for threshold in &self.thresholds {
+            let statistics = &results.statistics;
+            let verified = statistics.verified.get(&threshold.factor).copied().unwrap_or_default();
+            let actual_percentage = match statistics.total {
+                0 => 100,
+                total => 100 * verified / total,
+            };
+
+            if actual_percentage < threshold.minimum_percentage {
+                violations.push(PolicyViolation::UnmetThreshold {
+                    factor: threshold.factor,
+                    minimum_percentage: threshold.minimum_percentage,
+                    actual_percentage,
+                });
+            }
+        }
+
+        violations
+    }
+
+    fn applies_to(
+        &self,
+        requirement: &PolicyRequirement,
+        cargo_package: &CargoPackage,
+        results: &AnalysisResults,
+    ) -> bool {
+        let in_scope = match requirement.scope {
+            PolicyScope::All => true,
+            PolicyScope::Direct => results.tier_of(cargo_package) == Some(DependencyTier::Direct),
+        };
+
+        in_scope && matches_pattern(&requirement.crates, &cargo_package.name)
+    }
+}
+
+// Supports '*' wildcards only, e.g. "serde*" or "*-sys"
+fn matches_pattern(pattern: &str, crate_name: &str) -> bool {
+    let fragments = pattern.split('*').collect::<Vec<_>>();
+    let last_index = fragments.len() - 1;
+    let mut remaining = crate_name;
+
+    for (index, fragment) in fragments.iter().enumerate() {
+        if index == last_index && index > 0 {
+            return remaining.ends_with(fragment);
+        }
+
+        let position = match index {
+            0 => remaining.strip_prefix(fragment).map(|_| 0),
+            _ => remaining.find(fragment),
+        };
+
+        let Some(position) = position else {
+            return false;
+        };
+
+        remaining = &remaining[position + fragment.len()..];
+    }
+
+    remaining.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{
+        AnalysisResults, CargoPackage, CrateVeracityChecks, FactorCheck, FactorStatus, PackageSource,
+        StatisticsForPackages, UnverifiablePackage, VeracityFactor,
+    };
+    use crate::core::policies::{
+        PolicyExemption, PolicyRequirement, PolicyScope, PolicyThreshold, PolicyViolation, TrustPolicy, matches_pattern,
+    };
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use jiff::civil::date;
+    use std::collections::BTreeMap;
+
+    fn analysed(name: &str, trusted_publishing: bool) -> (CargoPackage, Option<CrateVeracityChecks>) {
+        let check = match trusted_publishing {
+            true => FactorCheck {
+                status: FactorStatus::Verified,
+                evidence: None,
+            },
+            false => FactorCheck::missing(),
+        };
+
+        let checks = CrateVeracityChecks::from([(VeracityFactor::TrustedPublishing, check)]);
+        (CargoPackage::with(name, "1.0.0"), Some(checks))
+    }
+
+    fn depth_of(cargo_package: &CargoPackage) -> usize {
+        match cargo_package.name.as_str() {
+            "openssl-sys" => 1,
+            _ => 2,
+        }
+    }
+
+    fn analysis_results() -> AnalysisResults {
+        let outcomes = vec![
+            analysed("tokio", true),
+            analysed("tokio-macros", false),
+            analysed("openssl-sys", false),
+        ];
+
+        AnalysisResults {
+            statistics: StatisticsForPackages {
+                total: 3,
+                unverifiable: 0,
+                verified: BTreeMap::from([(VeracityFactor::TrustedPublishing, 1)]),
+                mismatches: BTreeMap::from([(VeracityFactor::TrustedPublishing, 0)]),
+                publishers: BTreeMap::new(),
+                tiers: BTreeMap::new(),
+            },
+            depths: outcomes
+                .iter()
+                .map(|(package, _)| (package.clone(), depth_of(package)))
+                .collect(),
+            outcomes,
+            unverifiable: vec![],
+        }
+    }
+
+    fn requirement(scope: PolicyScope, crates: &str) -> PolicyRequirement {
+        PolicyRequirement {
+            factor: VeracityFactor::TrustedPublishing,
+            scope,
+            crates: crates.to_string(),
+        }
+    }
+
+    #[test]
+    fn should_match_crate_name_patterns() {
+        assertor::assert_that!(matches_pattern("*", "tokio")).is_true();
+        assertor::assert_that!(matches_pattern("tokio", "tokio")).is_true();
+        assertor::assert_that!(matches_pattern("tokio*", "tokio-macros")).is_true();
+        assertor::assert_that!(matches_pattern("*-sys", "openssl-sys")).is_true();
+        assertor::assert_that!(matches_pattern("*ssl*", "openssl-sys")).is_true();
+        assertor::assert_that!(matches_pattern("tokio", "tokio-macros")).is_false();
+        assertor::assert_that!(matches_pattern("*-sys", "openssl")).is_false();
+    }
+
+    #[test]
+    fn should_report_unmet_requirements_for_matching_crates() {
+        let policy = TrustPolicy {
+            requirements: vec![requirement(PolicyScope::All, "tokio*")],
+            ..Default::default()
+        };
+
+        let violations = policy.evaluate(&analysis_results(), date(2025, 10, 1));
+
+        let expected = PolicyViolation::UnmetRequirement {
+            cargo_package: CargoPackage::with("tokio-macros", "1.0.0"),
+            factor: VeracityFactor::TrustedPublishing,
+            status: Some(FactorStatus::Missing),
+        };
+
+        assertor::assert_that!(violations).is_equal_to(vec![expected]);
+    }
+
+    #[test]
+    fn should_report_unmet_requirements_for_direct_dependencies() {
+        let policy = TrustPolicy {
+            requirements: vec![requirement(PolicyScope::Direct, "*")],
+            ..Default::default()
+        };
+
+        let violations = policy.evaluate(&analysis_results(), date(2025, 10, 1));
+
+        let expected = PolicyViolation::UnmetRequirement {
+            cargo_package: CargoPackage::with("openssl-sys", "1.0.0"),
+            factor: VeracityFactor::TrustedPublishing,
+            status: Some(FactorStatus::Missing),
+        };
+
+        assertor::assert_that!(violations).is_equal_to(vec![expected]);
+    }
+
+    #[test]
+    fn should_report_unverifiable_direct_dependencies_unless_exempted() {
+        let git_checkout = UnverifiablePackage {
+            cargo_package: CargoPackage::with("forked-crate", "0.1.0"),
+            source: PackageSource::Git {
+                url: "https://github.com/someone/forked-crate".to_string(),
+                revision: Some("bbd8b099".to_string()),
+            },
+        };
+
+        let mut results = analysis_results();
+        results.depths.insert(git_checkout.cargo_package.clone(), 1);
+        results.unverifiable.push(git_checkout.clone());
+
+        let policy = TrustPolicy {
+            requirements: vec![requirement(PolicyScope::Direct, "forked-*")],
+            ..Default::default()
+        };
+
+        let violations = policy.evaluate(&results, date(2025, 10, 1));
+
+        let expected = PolicyViolation::UnverifiableSource {
+            cargo_package: git_checkout.cargo_package.clone(),
+            factor: VeracityFactor::TrustedPublishing,
+            source: git_checkout.source.clone(),
+        };
+
+        assertor::assert_that!(violations).is_equal_to(vec![expected]);
+
+        let exempting = TrustPolicy {
+            exemptions: vec![PolicyExemption {
+                crate_name: "forked-crate".to_string(),
+                version: None,
+                factor: None,
+                reason: "Waiting for upstream release".to_string(),
+                expires: date(2025, 12, 31),
+            }],
+            ..policy
+        };
+
+        assertor::assert_that!(exempting.evaluate(&results, date(2025, 10, 1)).is_empty()).is_true();
+    }
+
+    #[test]
+    fn should_honor_exemptions_until_they_expire() {
+        let policy = TrustPolicy {
+            requirements: vec![requirement(PolicyScope::Direct, "*")],
+            exemptions: vec![PolicyExemption {
+                crate_name: "openssl-sys".to_string(),
+                version: None,
+                factor: Some(VeracityFactor::TrustedPublishing),
+                reason: "Waiting for upstream adoption".to_string(),
+                expires: date(2025, 12, 31),
+            }],
+            ..Default::default()
+        };
+
+        let before_expiration = policy.evaluate(&analysis_results(), date(2025, 12, 31));
+        assertor::assert_that!(before_expiration.is_empty()).is_true();
+
+        let after_expiration = policy.evaluate(&analysis_results(), date(2026, 1, 1));
+        assertor::assert_that!(after_expiration.len()).is_equal_to(2);
+    }
+
+    #[test]
+    fn should_report_unmet_thresholds() {
+        let policy = TrustPolicy {
+            thresholds: vec![PolicyThreshold {
+                factor: VeracityFactor::TrustedPublishing,
+                minimum_percentage: 50,
+            }],
+            ..Default::default()
+        };
+
+        let violations = policy.evaluate(&analysis_results(), date(2025, 10, 1));
+
+        let expected = PolicyViolation::UnmetThreshold {
+            factor: VeracityFactor::TrustedPublishing,
+            minimum_percentage: 50,
+            actual_percentage: 33,
+        };
+
+        assertor::assert_that!(violations).is_equal_to(vec![expected]);
+    }
+}
@@ -1,7 +1,11 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+pub mod baselines;
 pub mod caching;
 pub mod cli;
+pub mod lockfiles;
 pub mod networking;
+pub mod policies;
 pub mod reporting;
+pub mod sboms;
@@ -0,0 +1,58 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::baselines::Baseline;
+use anyhow::Context;
+use std::fs;
+use std::path::Path;
+
+pub fn load_baseline(baseline_file: &Path) -> anyhow::Result<Baseline> {
+    let contents = fs::read(baseline_file)
+        .with_context(|| format!("pollux.baselines : cannot read baseline file ({:?})", baseline_file))?;
+    let baseline = serde_json::from_slice(&contents).context("pollux.baselines : invalid baseline file")?;
+    Ok(baseline)
+}
+
+pub fn write_baseline(baseline_file: &Path, baseline: &Baseline) -> anyhow::Result<()> {
+    let serialized = serde_json::to_vec_pretty(baseline)?;
+    fs::write(baseline_file, serialized)
+        .with_context(|| format!("pollux.baselines : cannot write baseline file ({:?})", baseline_file))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::baselines::{Baseline, BaselineEntry, UnverifiableBaselineEntry};
+    use crate::core::models::{FactorStatus, VeracityFactor};
+    use crate::infra::baselines::{load_baseline, write_baseline};
+    use assertor::EqualityAssertion;
+    use std::collections::BTreeMap;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_write_and_load_baseline() {
+        let baseline = Baseline {
+            packages: vec![BaselineEntry {
+                name: "tokio".to_string(),
+                version: "1.47.0".to_string(),
+                factors: BTreeMap::from([
+                    (VeracityFactor::TrustedPublishing, FactorStatus::Verified),
+                    (VeracityFactor::ReproducibleBuilds, FactorStatus::Missing),
+                ]),
+            }],
+            unverifiable: vec![UnverifiableBaselineEntry {
+                name: "acme-utils".to_string(),
+                version: "0.1.0".to_string(),
+                source: "git https://github.com/acme/acme-utils#a1b2c3".to_string(),
+            }],
+        };
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let baseline_file = temp_dir.path().join("pollux-baseline.json");
+
+        write_baseline(&baseline_file, &baseline).unwrap();
+        let restored = load_baseline(&baseline_file).unwrap();
+
+        assertor::assert_that!(restored).is_equal_to(baseline);
+    }
+}
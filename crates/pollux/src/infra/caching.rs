@@ -9,6 +9,10 @@ pub mod analysis;
 static CACHE_FOLDER_ANALYSED: &str = "analysed";
 static CACHE_FOLDER_PACKAGES: &str = "packages";
 static TEMP_DOWNLOADS_FOLDER: &str = "downloads";
+static SCRATCH_FOLDER: &str = "scratch";
+static DATABASE_DUMP_FOLDER: &str = "dbdump";
+static SPARSE_INDEX_FOLDER: &str = "index";
+static OSS_REBUILD_SNAPSHOT_FILE: &str = "ossrebuild/snapshot.json";
 
 pub struct CacheManager {
     cache_dir: PathBuf,
@@ -27,6 +31,22 @@ impl CacheManager {
         self.cache_dir.join(TEMP_DOWNLOADS_FOLDER)
     }
 
+    pub fn scratch_dir(&self) -> PathBuf {
+        self.cache_dir.join(SCRATCH_FOLDER)
+    }
+
+    pub fn database_dump_dir(&self) -> PathBuf {
+        self.cache_dir.join(DATABASE_DUMP_FOLDER)
+    }
+
+    pub fn sparse_index_cache_dir(&self) -> PathBuf {
+        self.cache_dir.join(SPARSE_INDEX_FOLDER)
+    }
+
+    pub fn rebuilds_snapshot_file(&self) -> PathBuf {
+        self.cache_dir.join(OSS_REBUILD_SNAPSHOT_FILE)
+    }
+
     pub fn analysis_cache_dir(&self) -> PathBuf {
         self.cache_dir.join(CACHE_FOLDER_ANALYSED)
     }
@@ -2,20 +2,18 @@
 // SPDX-License-Identifier: MIT
 
 use crate::core::interfaces::AnalyzedDataStorage;
-use crate::core::models::{CargoPackage, CrateVeracityChecks};
+use crate::core::models::{CargoPackage, CrateVeracityChecks, FactorCheck, FactorEvidence, VeracityFactor};
 use crate::infra::caching::CacheManager;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
-use std::str::FromStr;
-use url::Url;
 
 static VERACITY_CHECKS_FILE_NAME: &str = "checks.json";
 
 #[derive(Debug, Serialize, Deserialize)]
 struct CachedVeracityInfo {
     crate_purl: String,
-    trusted_publishing: Option<String>,
-    reproducibility: Option<String>,
+    factors: BTreeMap<VeracityFactor, FactorCheck<FactorEvidence>>,
 }
 
 pub struct AnalysedPackagesCache {
@@ -46,14 +44,13 @@ impl AnalyzedDataStorage for AnalysedPackagesCache {
         }
 
         log::info!("[pollux.cache] cache hit at {:?}", cache_file);
-        let serialized = std::fs::read(cache_file)?;
-        let info: CachedVeracityInfo = serde_json::from_slice(&serialized)?;
-        let checks = CrateVeracityChecks::new(
-            info.trusted_publishing
-                .map(|url| Url::from_str(&url).expect("cannot parse cache url")),
-            info.reproducibility
-                .map(|url| Url::from_str(&url).expect("cannot parse cache url")),
-        );
+        let serialized = std::fs::read(&cache_file)?;
+        let Ok(info) = serde_json::from_slice::<CachedVeracityInfo>(&serialized) else {
+            log::info!("[pollux.cache] discarding outdated cache entry at {:?}", cache_file);
+            return Ok(None);
+        };
+
+        let checks = CrateVeracityChecks { factors: info.factors };
         Ok(Some(checks))
     }
 
@@ -68,8 +65,7 @@ impl AnalyzedDataStorage for AnalysedPackagesCache {
 
         let cached_veracity = CachedVeracityInfo {
             crate_purl: crate_info.to_string(),
-            trusted_publishing: checks.trusted_publishing_evidence.map(|url| url.to_string()),
-            reproducibility: checks.reproducibility_evidence.map(|url| url.to_string()),
+            factors: checks.factors,
         };
 
         let serialized = serde_json::to_vec(&cached_veracity)?;
@@ -78,3 +74,75 @@ impl AnalyzedDataStorage for AnalysedPackagesCache {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{
+        AttestedSubject, CrateVeracityChecks, FactorCheck, FactorEvidence, PublishingProvider,
+        RepositoryConsistencyEvidence, ReproducibilityEvidence, TrustedPublishingEvidence, VcsProvenanceEvidence,
+        VeracityFactor,
+    };
+    use crate::infra::caching::analysis::CachedVeracityInfo;
+    use assertor::EqualityAssertion;
+    use std::str::FromStr;
+    use url::Url;
+
+    #[test]
+    fn should_restore_cached_factor_checks() {
+        let publishing = TrustedPublishingEvidence {
+            provider: PublishingProvider::GitHub,
+            repository: "fake-org/fake-crate".to_string(),
+            workflow_run: Url::from_str("https://shortn.ed/actions/runs/12345789").unwrap(),
+            commit_sha: "bbd8b099ea52bf4de18051d012c8113cf0dca23a".to_string(),
+        };
+
+        let mut reproducibility =
+            ReproducibilityEvidence::new(Url::from_str("https://shortn.ed/rebuild.intoto.jsonl").unwrap());
+        reproducibility.subjects.push(AttestedSubject {
+            name: "fake-crate-1.2.3.crate".to_string(),
+            sha256: None,
+        });
+
+        let repository_consistency = RepositoryConsistencyEvidence {
+            declared_repository: None,
+            publishing_repository: publishing.repository_url(),
+            path_in_vcs: None,
+        };
+
+        let vcs_provenance = VcsProvenanceEvidence {
+            commit_sha: publishing.commit_sha.clone(),
+            path_in_vcs: None,
+            dirty: false,
+            publishing_commit_sha: None,
+        };
+
+        let checks = CrateVeracityChecks::from([
+            (
+                VeracityFactor::TrustedPublishing,
+                FactorCheck::verified(FactorEvidence::TrustedPublishing(publishing)),
+            ),
+            (
+                VeracityFactor::ReproducibleBuilds,
+                FactorCheck::verified(FactorEvidence::Reproducibility(reproducibility)),
+            ),
+            (
+                VeracityFactor::RepositoryConsistency,
+                FactorCheck::verified(FactorEvidence::RepositoryConsistency(repository_consistency)),
+            ),
+            (
+                VeracityFactor::VcsProvenance,
+                FactorCheck::verified(FactorEvidence::VcsProvenance(vcs_provenance)),
+            ),
+        ]);
+
+        let cached = CachedVeracityInfo {
+            crate_purl: "pkg:cargo/fake-crate@1.2.3".to_string(),
+            factors: checks.factors.clone(),
+        };
+
+        let serialized = serde_json::to_vec(&cached).unwrap();
+        let restored = serde_json::from_slice::<CachedVeracityInfo>(&serialized).unwrap();
+
+        assertor::assert_that!(restored.factors).is_equal_to(checks.factors);
+    }
+}
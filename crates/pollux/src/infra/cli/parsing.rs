@@ -1,17 +1,90 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::{CargoPackage, CleanupScope, InquireCoverage, InquireReportKind};
+use crate::core::models::{
+    CleanupScope, CrateReference, DatabaseDumpLocation, DependencyKind, InquireCoverage, InquireReportKind,
+    MAX_CRATESIO_BURST, MAX_CRATESIO_REQUESTS_PER_SECOND, MetadataFilters, RateLimits, RebuildsSnapshotLocation,
+    RequestedVersion, VersionSelection,
+};
 use crate::infra::cli::parsing::MainCommands::Analyse;
-use crate::pollux::PolluxTask;
-use anyhow::bail;
+use crate::infra::{baselines, lockfiles, policies, sboms};
+use crate::pollux::{BaselineUsage, PolluxSettings, PolluxTask};
+use anyhow::{Context, bail};
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use semver::VersionReq;
 use std::path::PathBuf;
 
 #[derive(ValueEnum, Debug, Clone)]
 enum AnalysisSubject {
     Project,
     Crate,
+    Lockfile,
+    Sbom,
+}
+
+#[derive(Args, Debug)]
+struct ResolutionArguments {
+    /// Whether to resolve dependencies from `cargo metadata` instead of Cargo.lock
+    #[arg(
+        long,
+        action,
+        default_value = "false",
+        help = "Resolve dependencies with cargo metadata, honoring targets, features and kinds"
+    )]
+    pub cargo_metadata: bool,
+
+    /// Target triple to resolve platform-specific dependencies for (e.g. x86_64-unknown-linux-gnu)
+    #[arg(long, requires = "cargo_metadata")]
+    pub target: Option<String>,
+
+    /// Comma-separated list of features to activate
+    #[arg(long, value_delimiter = ',', requires = "cargo_metadata")]
+    pub features: Vec<String>,
+
+    /// Whether to activate all available features
+    #[arg(long, action, requires = "cargo_metadata", conflicts_with = "features")]
+    pub all_features: bool,
+
+    /// Whether to skip the default features
+    #[arg(long, action, requires = "cargo_metadata")]
+    pub no_default_features: bool,
+
+    /// Comma-separated list of dependency kinds to follow
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "normal,build,dev",
+        requires = "cargo_metadata"
+    )]
+    pub kinds: Vec<DependencyKind>,
+
+    /// Whether to refuse generating a missing Cargo.lock
+    #[arg(
+        long,
+        action,
+        default_value = "false",
+        help = "Fail when the project has no Cargo.lock, instead of generating one"
+    )]
+    pub locked: bool,
+}
+
+impl ResolutionArguments {
+    fn metadata_filters(self) -> Option<MetadataFilters> {
+        if !self.cargo_metadata {
+            return None;
+        }
+
+        let filters = MetadataFilters {
+            target: self.target,
+            features: self.features,
+            all_features: self.all_features,
+            no_default_features: self.no_default_features,
+            kinds: self.kinds,
+        };
+
+        Some(filters)
+    }
 }
 
 #[derive(Args, Debug)]
@@ -21,9 +94,83 @@ struct AnalysisArguments {
     #[arg(value_enum)]
     pub subject: AnalysisSubject,
 
-    /// Folder path or crate package url (purl) to analyse
+    /// Folder path, crate package url (purl), lockfile path ('-' for stdin) or SBOM file to analyse
     pub input: String,
 
+    /// How to pick a version when the crate package url (purl) has none
+    #[arg(long, value_enum, default_value = "default")]
+    pub version_selection: VersionSelection,
+
+    /// Baseline file to compare analysis results against
+    #[arg(long, conflicts_with = "write_baseline")]
+    pub baseline: Option<PathBuf>,
+
+    /// Baseline file to write analysis results into
+    #[arg(long)]
+    pub write_baseline: Option<PathBuf>,
+
+    /// Whether to break down results per workspace member
+    #[arg(
+        long,
+        action,
+        default_value = "false",
+        help = "Analyse the dependency closure of each workspace member separately"
+    )]
+    pub per_member: bool,
+
+    /// Whether to download crate sources for source-based veracity checks
+    #[arg(
+        long,
+        action,
+        default_value = "false",
+        help = "Download crate sources to check repository and VCS provenance"
+    )]
+    pub inspect_sources: bool,
+
+    #[command(flatten)]
+    pub resolution: ResolutionArguments,
+
+    /// Whether to use colored output
+    #[arg(
+        short,
+        long,
+        action,
+        default_value = "false",
+        help = "Dont use colors on console output"
+    )]
+    pub no_color: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct VerifyArguments {
+    /// Subject specification (Rust project or crate)
+    #[arg(value_enum)]
+    pub subject: AnalysisSubject,
+
+    /// Folder path, crate package url (purl), lockfile path ('-' for stdin) or SBOM file to verify
+    pub input: String,
+
+    /// How to pick a version when the crate package url (purl) has none
+    #[arg(long, value_enum, default_value = "default")]
+    pub version_selection: VersionSelection,
+
+    /// Policy file defining trust requirements
+    #[arg(short, long, default_value = "pollux.toml")]
+    pub policy: PathBuf,
+
+    /// Whether to download crate sources for source-based veracity checks
+    #[arg(
+        long,
+        action,
+        default_value = "false",
+        help = "Download crate sources to check repository and VCS provenance"
+    )]
+    pub inspect_sources: bool,
+
+    #[command(flatten)]
+    pub resolution: ResolutionArguments,
+
     /// Whether to use colored output
     #[arg(
         short,
@@ -41,6 +188,74 @@ struct CheckArguments {
     /// Crate package url (purl) to check
     pub input: String,
 
+    /// How to pick a version when the crate package url (purl) has none
+    #[arg(long, value_enum, default_value = "default")]
+    pub version_selection: VersionSelection,
+
+    /// Whether to download crate sources for source-based veracity checks
+    #[arg(
+        long,
+        action,
+        default_value = "false",
+        help = "Download crate sources to check repository and VCS provenance"
+    )]
+    pub inspect_sources: bool,
+
+    /// Whether to use colored output
+    #[arg(
+        short,
+        long,
+        action,
+        default_value = "false",
+        help = "Dont use colors on console output"
+    )]
+    pub no_color: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct HistoryArguments {
+    /// Crate package url (purl) without version, or plain crate name
+    pub input: String,
+
+    /// Semver requirement narrowing down versions to check (e.g. ">=1.0.200, <2")
+    #[arg(long)]
+    pub versions: Option<String>,
+
+    /// Whether to use colored output
+    #[arg(
+        short,
+        long,
+        action,
+        default_value = "false",
+        help = "Dont use colors on console output"
+    )]
+    pub no_color: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(version, about, long_about = None)]
+struct WhyArguments {
+    /// Crate name to explain, optionally pinned to a version (e.g. libc@0.2.175)
+    #[arg(value_name = "CRATE")]
+    pub crate_spec: String,
+
+    /// Folder path for the Rust project to inspect
+    #[arg(short, long, default_value = ".")]
+    pub project: PathBuf,
+
+    /// Whether to download crate sources for source-based veracity checks
+    #[arg(
+        long,
+        action,
+        default_value = "false",
+        help = "Download crate sources to check repository and VCS provenance"
+    )]
+    pub inspect_sources: bool,
+
+    #[command(flatten)]
+    pub resolution: ResolutionArguments,
+
     /// Whether to use colored output
     #[arg(
         short,
@@ -98,51 +313,343 @@ struct InquiringArguments {
 struct CliParser {
     #[command(subcommand)]
     pub command: MainCommands,
+
+    /// Sustained rate of requests sent to crates.io, shared by every check
+    #[arg(long, global = true, default_value = "0.9")]
+    pub cratesio_rate: f64,
+
+    /// Amount of requests to crates.io that may be sent back-to-back after idling
+    #[arg(long, global = true, default_value = "1")]
+    pub cratesio_burst: u32,
+
+    /// crates.io database dump to check trusted publishing against ('latest' downloads it)
+    #[arg(long, global = true)]
+    pub db_dump: Option<String>,
+
+    /// Amount of oss-rebuild lookups running concurrently
+    #[arg(long, global = true, default_value = "16", value_parser = clap::value_parser!(u16).range(1..=64))]
+    pub rebuild_parallelism: u16,
+
+    /// Listing of oss-rebuild attestations to answer lookups from ('latest' lists the bucket)
+    #[arg(long, global = true)]
+    pub rebuilds_snapshot: Option<String>,
+}
+
+impl CliParser {
+    fn rate_limits(&self) -> anyhow::Result<RateLimits> {
+        if self.cratesio_rate <= 0.0 || self.cratesio_rate > MAX_CRATESIO_REQUESTS_PER_SECOND {
+            bail!(
+                "pollux.cli : crates.io rate must be within (0, {}] requests per second",
+                MAX_CRATESIO_REQUESTS_PER_SECOND
+            )
+        }
+
+        if self.cratesio_burst == 0 || self.cratesio_burst > MAX_CRATESIO_BURST {
+            bail!(
+                "pollux.cli : crates.io burst must be within [1, {}]",
+                MAX_CRATESIO_BURST
+            )
+        }
+
+        let rate_limits = RateLimits {
+            requests_per_second: self.cratesio_rate,
+            burst: self.cratesio_burst,
+        };
+
+        Ok(rate_limits)
+    }
+
+    fn database_dump(&self) -> anyhow::Result<Option<DatabaseDumpLocation>> {
+        let location = match self.db_dump.as_deref() {
+            None => return Ok(None),
+            Some("latest") => DatabaseDumpLocation::Latest,
+            Some(dump_file) => {
+                let dump_path = PathBuf::from(dump_file);
+                if !dump_path.exists() {
+                    bail!("pollux.cli : no such file or directory ({:?})", dump_path)
+                }
+                DatabaseDumpLocation::LocalFile(dump_path)
+            },
+        };
+
+        Ok(Some(location))
+    }
+
+    fn rebuilds_snapshot(&self) -> anyhow::Result<Option<RebuildsSnapshotLocation>> {
+        let location = match self.rebuilds_snapshot.as_deref() {
+            None => return Ok(None),
+            Some("latest") => RebuildsSnapshotLocation::Latest,
+            Some(snapshot_file) => {
+                let snapshot_path = PathBuf::from(snapshot_file);
+                if !snapshot_path.exists() {
+                    bail!("pollux.cli : no such file or directory ({:?})", snapshot_path)
+                }
+                RebuildsSnapshotLocation::LocalFile(snapshot_path)
+            },
+        };
+
+        Ok(Some(location))
+    }
 }
 
 #[derive(Subcommand)]
 enum MainCommands {
     /// Check existing veracity factor for a single package
     Check(CheckArguments),
+    /// Check veracity factors for every published version of a crate
+    History(HistoryArguments),
     /// Clean up existing cached data used by this tool
     Cleanup(CleanupArguments),
     /// Analyse veracity checks for a target Rust project or crate
     Analyse(AnalysisArguments),
+    /// Verify a target Rust project or crate against a trust policy
+    Verify(VerifyArguments),
+    /// Explain how a crate enters the dependency graph of a Rust project
+    Why(WhyArguments),
     /// Evaluate veracity checks for the top packages served by crates.io
     Inquire(InquiringArguments),
 }
 
-pub fn parse_arguments() -> anyhow::Result<(PolluxTask, bool)> {
+pub fn parse_arguments() -> anyhow::Result<(PolluxTask, PolluxSettings)> {
     let cli = CliParser::parse();
+    let rate_limits = cli.rate_limits()?;
+    let database_dump = cli.database_dump()?;
+    let rebuilds_snapshot = cli.rebuilds_snapshot()?;
 
-    let (task, turnoff_colors) = match cli.command {
-        Analyse(args) => match args.subject {
-            AnalysisSubject::Project => {
-                let project_path = PathBuf::from(args.input);
-                if !project_path.exists() {
-                    bail!("pollux.cli : no such file or directory ({:?})", project_path)
-                }
-                (PolluxTask::AnalyseRustProject(project_path), args.no_color)
-            },
-            AnalysisSubject::Crate => {
-                let cargo_package = CargoPackage::try_from(args.input)?;
-                (PolluxTask::AnalyseRustCrate(cargo_package), args.no_color)
-            },
+    let (task, mut settings) = match cli.command {
+        Analyse(args) => {
+            let settings = PolluxSettings {
+                turnoff_colors: args.no_color,
+                inspect_sources: args.inspect_sources,
+                locked: args.resolution.locked,
+                metadata_filters: args.resolution.metadata_filters(),
+                ..Default::default()
+            };
+
+            let baseline_usage = match (args.baseline, args.write_baseline) {
+                (Some(baseline_file), _) => BaselineUsage::Compare(baselines::load_baseline(&baseline_file)?),
+                (None, Some(baseline_file)) => BaselineUsage::Write(baseline_file),
+                (None, None) => BaselineUsage::Skip,
+            };
+
+            match args.subject {
+                AnalysisSubject::Project => {
+                    let project_path = PathBuf::from(args.input);
+                    if !project_path.exists() {
+                        bail!("pollux.cli : no such file or directory ({:?})", project_path)
+                    }
+                    match args.per_member {
+                        true => (
+                            PolluxTask::AnalyseWorkspaceMembers(project_path, baseline_usage),
+                            settings,
+                        ),
+                        false => (PolluxTask::AnalyseRustProject(project_path, baseline_usage), settings),
+                    }
+                },
+                AnalysisSubject::Crate => {
+                    if args.per_member {
+                        bail!("pollux.cli : per-member analysis applies to Rust projects only")
+                    }
+
+                    let crate_reference = CrateReference::parse(&args.input, args.version_selection)?;
+                    (PolluxTask::AnalyseRustCrate(crate_reference, baseline_usage), settings)
+                },
+                AnalysisSubject::Lockfile => {
+                    if args.per_member {
+                        bail!("pollux.cli : per-member analysis applies to Rust projects only")
+                    }
+
+                    if settings.metadata_filters.is_some() {
+                        bail!("pollux.cli : lockfiles are analysed as-is, without invoking cargo")
+                    }
+
+                    let lockfile = lockfiles::load_lockfile(&args.input)?;
+                    (PolluxTask::AnalyseLockfile(lockfile, baseline_usage), settings)
+                },
+                AnalysisSubject::Sbom => {
+                    if args.per_member {
+                        bail!("pollux.cli : per-member analysis applies to Rust projects only")
+                    }
+
+                    if settings.metadata_filters.is_some() {
+                        bail!("pollux.cli : SBOMs are analysed as-is, without invoking cargo")
+                    }
+
+                    let dependencies = sboms::load_sbom(&PathBuf::from(args.input))?;
+                    (PolluxTask::AnalyseSbom(dependencies, baseline_usage), settings)
+                },
+            }
+        },
+        MainCommands::Verify(args) => {
+            let policy = policies::load_policy(&args.policy)?;
+
+            // Source-based factors must be evaluated whenever the policy relies on them
+            let settings = PolluxSettings {
+                turnoff_colors: args.no_color,
+                inspect_sources: args.inspect_sources
+                    || policy.factors().iter().any(|factor| factor.requires_sources()),
+                locked: args.resolution.locked,
+                metadata_filters: args.resolution.metadata_filters(),
+                ..Default::default()
+            };
+
+            match args.subject {
+                AnalysisSubject::Project => {
+                    let project_path = PathBuf::from(args.input);
+                    if !project_path.exists() {
+                        bail!("pollux.cli : no such file or directory ({:?})", project_path)
+                    }
+                    (PolluxTask::VerifyRustProject(project_path, policy), settings)
+                },
+                AnalysisSubject::Crate => {
+                    let crate_reference = CrateReference::parse(&args.input, args.version_selection)?;
+                    (PolluxTask::VerifyRustCrate(crate_reference, policy), settings)
+                },
+                AnalysisSubject::Lockfile => {
+                    if settings.metadata_filters.is_some() {
+                        bail!("pollux.cli : lockfiles are verified as-is, without invoking cargo")
+                    }
+
+                    let lockfile = lockfiles::load_lockfile(&args.input)?;
+                    (PolluxTask::VerifyLockfile(lockfile, policy), settings)
+                },
+                AnalysisSubject::Sbom => {
+                    if settings.metadata_filters.is_some() {
+                        bail!("pollux.cli : SBOMs are verified as-is, without invoking cargo")
+                    }
+
+                    let dependencies = sboms::load_sbom(&PathBuf::from(args.input))?;
+                    (PolluxTask::VerifySbom(dependencies, policy), settings)
+                },
+            }
+        },
+        MainCommands::Why(args) => {
+            let settings = PolluxSettings {
+                turnoff_colors: args.no_color,
+                inspect_sources: args.inspect_sources,
+                locked: args.resolution.locked,
+                metadata_filters: args.resolution.metadata_filters(),
+                ..Default::default()
+            };
+
+            if !args.project.exists() {
+                bail!("pollux.cli : no such file or directory ({:?})", args.project)
+            }
+
+            let (crate_name, crate_version) = match args.crate_spec.split_once('@') {
+                Some((name, version)) => (name.to_string(), Some(version.to_string())),
+                None => (args.crate_spec, None),
+            };
+
+            (
+                PolluxTask::ExplainDependency(args.project, crate_name, crate_version),
+                settings,
+            )
         },
-        MainCommands::Cleanup(args) => match args.mode {
-            CleanupScope::Everything => (PolluxTask::CleanupEverything, args.no_color),
-            CleanupScope::AnalysedData => (PolluxTask::CleanupAnalysedData, args.no_color),
-            CleanupScope::PackageSources => (PolluxTask::CleanupPackageSource, args.no_color),
+        MainCommands::Cleanup(args) => {
+            let settings = PolluxSettings {
+                turnoff_colors: args.no_color,
+                ..Default::default()
+            };
+
+            match args.mode {
+                CleanupScope::Everything => (PolluxTask::CleanupEverything, settings),
+                CleanupScope::AnalysedData => (PolluxTask::CleanupAnalysedData, settings),
+                CleanupScope::PackageSources => (PolluxTask::CleanupPackageSource, settings),
+            }
         },
         MainCommands::Check(args) => {
-            let cargo_package = CargoPackage::try_from(args.input)?;
-            (PolluxTask::CheckRustCrate(cargo_package), args.no_color)
+            let settings = PolluxSettings {
+                turnoff_colors: args.no_color,
+                inspect_sources: args.inspect_sources,
+                ..Default::default()
+            };
+
+            let crate_reference = CrateReference::parse(&args.input, args.version_selection)?;
+            (PolluxTask::CheckRustCrate(crate_reference), settings)
+        },
+        MainCommands::History(args) => {
+            let settings = PolluxSettings {
+                turnoff_colors: args.no_color,
+                ..Default::default()
+            };
+
+            let crate_name = match args.input.starts_with("pkg:") {
+                true => {
+                    let crate_reference = CrateReference::parse(&args.input, VersionSelection::Default)?;
+
+                    if let RequestedVersion::Exact(version) = crate_reference.version {
+                        bail!("pollux.cli : expecting a purl without version, found {}", version)
+                    }
+
+                    crate_reference.name
+                },
+                false => args.input,
+            };
+
+            let requirement = args
+                .versions
+                .map(|versions| VersionReq::parse(&versions))
+                .transpose()
+                .context("pollux.cli : invalid semver requirement")?;
+
+            (PolluxTask::CheckCrateHistory(crate_name, requirement), settings)
+        },
+        MainCommands::Inquire(args) => {
+            let settings = PolluxSettings {
+                turnoff_colors: args.no_color,
+                ..Default::default()
+            };
+
+            (PolluxTask::InquirePopularCrates(args.output, args.coverage), settings)
         },
-        MainCommands::Inquire(args) => (
-            PolluxTask::InquirePopularCrates(args.output, args.coverage),
-            args.no_color,
-        ),
     };
 
-    Ok((task, turnoff_colors))
+    settings.rate_limits = rate_limits;
+    settings.database_dump = database_dump;
+    settings.rebuilds_snapshot = rebuilds_snapshot;
+    settings.rebuild_parallelism = cli.rebuild_parallelism as usize;
+    Ok((task, settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{MAX_CRATESIO_BURST, MAX_CRATESIO_REQUESTS_PER_SECOND};
+    use crate::infra::cli::parsing::CliParser;
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use clap::Parser;
+
+    fn parse_rate_limits(rate: &str, burst: &str) -> anyhow::Result<(f64, u32)> {
+        let arguments = [
+            "pollux",
+            "--cratesio-rate",
+            rate,
+            "--cratesio-burst",
+            burst,
+            "cleanup",
+            "everything",
+        ];
+        let cli = CliParser::try_parse_from(arguments)?;
+        let rate_limits = cli.rate_limits()?;
+        Ok((rate_limits.requests_per_second, rate_limits.burst))
+    }
+
+    #[test]
+    fn should_accept_small_bursts_within_cratesio_rate() {
+        let burst = MAX_CRATESIO_BURST.to_string();
+        let rate = MAX_CRATESIO_REQUESTS_PER_SECOND.to_string();
+
+        let parsed = parse_rate_limits(&rate, &burst).unwrap();
+
+        assertor::assert_that!(parsed).is_equal_to((MAX_CRATESIO_REQUESTS_PER_SECOND, MAX_CRATESIO_BURST));
+    }
+
+    #[test]
+    fn should_reject_rate_limits_above_cratesio_policy() {
+        let burst = (MAX_CRATESIO_BURST + 1).to_string();
+
+        assertor::assert_that!(parse_rate_limits("1.5", "1").is_err()).is_true();
+        assertor::assert_that!(parse_rate_limits("0.9", &burst).is_err()).is_true();
+        assertor::assert_that!(parse_rate_limits("0.9", "0").is_err()).is_true();
+    }
 }
@@ -0,0 +1,66 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use cargo_lock::Lockfile;
+use std::fs;
+use std::io::Read;
+use std::str::FromStr;
+
+// Reads from stdin when the input is '-', so lockfiles can be piped from `git show`
+pub fn load_lockfile(input: &str) -> anyhow::Result<Lockfile> {
+    let contents = match input {
+        "-" => {
+            let mut contents = String::new();
+            std::io::stdin()
+                .read_to_string(&mut contents)
+                .context("pollux.lockfiles : cannot read lockfile from stdin")?;
+            contents
+        },
+        lockfile_path => fs::read_to_string(lockfile_path)
+            .with_context(|| format!("pollux.lockfiles : cannot read lockfile ({:?})", lockfile_path))?,
+    };
+
+    let lockfile = Lockfile::from_str(&contents).context("pollux.lockfiles : invalid lockfile")?;
+    Ok(lockfile)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::lockfiles::load_lockfile;
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use std::fs;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_load_lockfile_from_path() {
+        let lockfile_contents = r#"
+            version = 3
+
+            [[package]]
+            name = "cfg-if"
+            version = "1.0.0"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+            checksum = "baf1de4339761588bc0619e3cbc0120ee582ebb74b53b4efbf79117bd2da40fd"
+        "#;
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let lockfile_path = temp_dir.path().join("Cargo.lock");
+        fs::write(&lockfile_path, lockfile_contents).expect("failed to write lockfile");
+
+        let lockfile = load_lockfile(lockfile_path.to_str().unwrap()).unwrap();
+
+        assertor::assert_that!(lockfile.packages.len()).is_equal_to(1);
+    }
+
+    #[test]
+    fn should_reject_invalid_lockfile() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let lockfile_path = temp_dir.path().join("Cargo.lock");
+        fs::write(&lockfile_path, "not a lockfile").expect("failed to write lockfile");
+
+        let loaded = load_lockfile(lockfile_path.to_str().unwrap());
+
+        assertor::assert_that!(loaded.is_err()).is_true();
+    }
+}
@@ -4,3 +4,4 @@
 pub mod crates;
 pub mod http;
 pub mod ossrebuild;
+pub mod ratelimiting;
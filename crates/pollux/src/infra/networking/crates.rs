@@ -2,44 +2,218 @@
 // SPDX-License-Identifier: MIT
 
 use crate::core::interfaces::VeracityFactorCheck;
-use crate::core::models::{CargoPackage, InquireCoverage};
-use crate::infra::networking::crates::registry::CratesDotIOClient;
+use crate::core::models::{
+    CargoPackage, CrateReference, CratesPublisher, FactorCheck, FactorEvidence, FactorStatus, InquireCoverage,
+    PublishedVersion, PublishingDowngradeEvidence, PublishingProvider, RequestedVersion, TrustedPublishingEvidence,
+    UserTokenPublishingEvidence, VersionSelection,
+};
+use crate::infra::networking::crates::dbdump::CratesDatabaseDump;
+use crate::infra::networking::crates::registry::{
+    CrateVersionDetails, CrateVersionsHistory, CratesDotIOClient, CratesUser, TrustPubData,
+};
+use crate::infra::networking::crates::sparse::SparseIndexClient;
+use anyhow::Context;
+use semver::{Version, VersionReq};
 use url::Url;
 
+pub mod dbdump;
+pub mod provenance;
 pub mod registry;
 pub mod resolvers;
+pub mod sparse;
 pub mod tarballs;
 
+pub enum CratesRegistryMetadata {
+    Api(CratesDotIOClient),
+    DatabaseDump(CratesDatabaseDump),
+}
+
+impl CratesRegistryMetadata {
+    async fn get_crate_version_details(
+        &self,
+        crate_name: &str,
+        crate_version: &str,
+    ) -> anyhow::Result<CrateVersionDetails> {
+        match self {
+            CratesRegistryMetadata::Api(client) => client.get_crate_version_details(crate_name, crate_version).await,
+            CratesRegistryMetadata::DatabaseDump(dump) => {
+                dump.get_crate_version_details(crate_name, crate_version).await
+            },
+        }
+    }
+
+    async fn get_crate_versions_history(&self, crate_name: &str) -> anyhow::Result<CrateVersionsHistory> {
+        match self {
+            CratesRegistryMetadata::Api(client) => client.get_crate_versions_history(crate_name).await,
+            CratesRegistryMetadata::DatabaseDump(dump) => dump.get_crate_versions_history(crate_name).await,
+        }
+    }
+}
+
 pub struct OfficialCratesRegistryChecker {
-    cratesio_client: CratesDotIOClient,
+    registry_metadata: CratesRegistryMetadata,
 }
 
 impl OfficialCratesRegistryChecker {
-    pub fn new(cratesio_client: CratesDotIOClient) -> Self {
-        Self { cratesio_client }
+    pub fn new(registry_metadata: CratesRegistryMetadata) -> Self {
+        Self { registry_metadata }
     }
-}
 
-impl VeracityFactorCheck for OfficialCratesRegistryChecker {
-    async fn execute(&self, crate_info: &CargoPackage) -> anyhow::Result<Option<Url>> {
+    // Versions missing from the history (e.g. published after it got memoised) are looked up on their own
+    async fn publishing_details(
+        &self,
+        crate_info: &CargoPackage,
+        history: &CrateVersionsHistory,
+    ) -> anyhow::Result<(Option<TrustPubData>, Option<CratesUser>)> {
+        if let Some(published) = history
+            .versions
+            .iter()
+            .find(|published| published.num == crate_info.version)
+        {
+            return Ok((published.trustpub_data.clone(), published.publisher()));
+        }
+
         let crate_details = self
-            .cratesio_client
+            .registry_metadata
             .get_crate_version_details(crate_info.name.as_str(), crate_info.version.as_str())
             .await?;
 
-        let Some(trustpub_data) = crate_details.version.trustpub_data else {
-            log::info!("[pollux.checker] trusted publishing not found for {}", crate_info);
-            return Ok(None);
+        let mut version_info = crate_details.version;
+        let trustpub_data = version_info.trustpub_data.take();
+        Ok((trustpub_data, version_info.publisher()))
+    }
+}
+
+impl VeracityFactorCheck for OfficialCratesRegistryChecker {
+    type Evidence = FactorEvidence;
+
+    // The memoised versions history answers every version of a crate, downgrades included,
+    // hence crates.io gets a single request per crate
+    async fn execute(&self, crate_info: &CargoPackage) -> anyhow::Result<FactorCheck<FactorEvidence>> {
+        let history = self
+            .registry_metadata
+            .get_crate_versions_history(crate_info.name.as_str())
+            .await?;
+
+        let (trustpub_data, publisher) = self.publishing_details(crate_info, &history).await?;
+
+        let previous_release = match trustpub_data {
+            Some(_) => None,
+            None => find_previous_release(crate_info, &history)?,
         };
 
-        let gha_run_url = format!(
-            "https://github.com/{}/actions/runs/{}",
-            trustpub_data.repository, trustpub_data.run_id
+        trusted_publishing_check(crate_info, trustpub_data, publisher, previous_release)
+    }
+}
+
+// Most recent earlier release that went through trusted publishing, if any
+fn find_previous_release(
+    crate_info: &CargoPackage,
+    history: &CrateVersionsHistory,
+) -> anyhow::Result<Option<(Version, TrustPubData)>> {
+    let checked_version = Version::parse(&crate_info.version)
+        .with_context(|| format!("pollux.checker : invalid version for {}", crate_info))?;
+
+    let previous_release = history
+        .versions
+        .iter()
+        .filter_map(|published| {
+            let version = Version::parse(&published.num).ok()?;
+            let trustpub_data = published.trustpub_data.clone()?;
+            (version < checked_version).then_some((version, trustpub_data))
+        })
+        .max_by(|(some_version, _), (other_version, _)| some_version.cmp(other_version));
+
+    Ok(previous_release)
+}
+
+fn trusted_publishing_check(
+    crate_info: &CargoPackage,
+    trustpub_data: Option<TrustPubData>,
+    publisher: Option<CratesUser>,
+    previous_release: Option<(Version, TrustPubData)>,
+) -> anyhow::Result<FactorCheck<FactorEvidence>> {
+    if let Some(trustpub_data) = trustpub_data {
+        let check = match convert_trustpub_data(crate_info, trustpub_data)? {
+            Some(evidence) => FactorCheck::verified(FactorEvidence::TrustedPublishing(evidence)),
+            None => FactorCheck::missing(),
+        };
+        return Ok(check);
+    }
+
+    log::info!("[pollux.checker] trusted publishing not found for {}", crate_info);
+
+    let published_by = publisher.map(|user| CratesPublisher {
+        login: user.login,
+        name: user.name,
+    });
+
+    if let Some((previous_version, trustpub_data)) = previous_release
+        && let Some(previous_publishing) = convert_trustpub_data(crate_info, trustpub_data)?
+    {
+        log::warn!(
+            "[pollux.checker] trusted publishing downgrade detected for {} (previously used at {})",
+            crate_info,
+            previous_version
         );
 
-        let attestation_url = Url::parse(gha_run_url.as_str())?;
-        Ok(Some(attestation_url))
+        let evidence = PublishingDowngradeEvidence {
+            previous_version: previous_version.to_string(),
+            previous_publishing,
+            published_by,
+        };
+
+        return Ok(FactorCheck::mismatch(FactorEvidence::PublishingDowngrade(evidence)));
     }
+
+    let check = match published_by {
+        Some(publisher) => FactorCheck {
+            status: FactorStatus::Missing,
+            evidence: Some(FactorEvidence::UserTokenPublishing(UserTokenPublishingEvidence {
+                published_by: publisher,
+            })),
+        },
+        None => FactorCheck::missing(),
+    };
+
+    Ok(check)
+}
+
+fn convert_trustpub_data(
+    crate_info: &CargoPackage,
+    trustpub_data: TrustPubData,
+) -> anyhow::Result<Option<TrustedPublishingEvidence>> {
+    let evidence = match trustpub_data {
+        TrustPubData::GitHub {
+            repository,
+            run_id,
+            sha,
+        } => TrustedPublishingEvidence {
+            provider: PublishingProvider::GitHub,
+            workflow_run: Url::parse(&format!("https://github.com/{}/actions/runs/{}", repository, run_id))?,
+            repository,
+            commit_sha: sha,
+        },
+        TrustPubData::GitLab {
+            project_path,
+            job_id,
+            sha,
+        } => TrustedPublishingEvidence {
+            provider: PublishingProvider::GitLab,
+            workflow_run: Url::parse(&format!("https://gitlab.com/{}/-/jobs/{}", project_path, job_id))?,
+            repository: project_path,
+            commit_sha: sha,
+        },
+        TrustPubData::Unsupported => {
+            log::warn!(
+                "[pollux.checker] unsupported trusted publishing provider for {}",
+                crate_info
+            );
+            return Ok(None);
+        },
+    };
+
+    Ok(Some(evidence))
 }
 
 pub struct PopularCratesFetcher {
@@ -81,42 +255,230 @@ impl PopularCratesFetcher {
     }
 }
 
+pub struct CrateVersionResolver {
+    sparse_index: SparseIndexClient,
+    cratesio_client: CratesDotIOClient,
+}
+
+impl CrateVersionResolver {
+    pub fn new(sparse_index: SparseIndexClient, cratesio_client: CratesDotIOClient) -> Self {
+        Self {
+            sparse_index,
+            cratesio_client,
+        }
+    }
+
+    pub async fn resolve(&self, crate_reference: CrateReference) -> anyhow::Result<CargoPackage> {
+        let selection = match crate_reference.version {
+            RequestedVersion::Exact(version) => {
+                self.sparse_index
+                    .get_crate_version(&crate_reference.name, &version)
+                    .await?;
+                return Ok(CargoPackage::new(crate_reference.name, version));
+            },
+            RequestedVersion::Selected(selection) => selection,
+        };
+
+        let indexed_versions = self
+            .sparse_index
+            .get_crate_versions(&crate_reference.name)
+            .await
+            .with_context(|| format!("pollux.versions : cannot fetch versions of {}", crate_reference.name))?;
+
+        // Mirrors crates.io, which only considers yanked versions when nothing else is left
+        let all_yanked = indexed_versions.iter().all(|indexed| indexed.yanked);
+        let candidates = indexed_versions
+            .iter()
+            .filter(|indexed| all_yanked || !indexed.yanked)
+            .filter_map(|indexed| Version::parse(&indexed.vers).ok())
+            .collect::<Vec<_>>();
+
+        let max_stable_version = candidates.iter().filter(|version| version.pre.is_empty()).max();
+        let max_version = candidates
+            .iter()
+            .max()
+            .with_context(|| format!("pollux.versions : no published version for {}", crate_reference.name))?;
+
+        // Crates whose releases are all prereleases lack default and stable versions
+        let resolved_version = match selection {
+            VersionSelection::Default => max_stable_version.unwrap_or(max_version).to_string(),
+            VersionSelection::LatestStable => max_stable_version
+                .with_context(|| format!("pollux.versions : no stable version for {}", crate_reference.name))?
+                .to_string(),
+            VersionSelection::Latest => max_version.to_string(),
+        };
+
+        log::info!(
+            "[pollux.versions] resolved {} to {} ({})",
+            crate_reference.name,
+            resolved_version,
+            selection
+        );
+
+        Ok(CargoPackage::new(crate_reference.name, resolved_version))
+    }
+
+    pub async fn published_versions(
+        &self,
+        crate_name: &str,
+        requirement: Option<&VersionReq>,
+    ) -> anyhow::Result<Vec<PublishedVersion>> {
+        let history = self
+            .cratesio_client
+            .get_crate_versions_history(crate_name)
+            .await
+            .with_context(|| format!("pollux.versions : cannot fetch versions of {}", crate_name))?;
+
+        let mut published = history
+            .versions
+            .into_iter()
+            .filter_map(|version| {
+                let parsed = Version::parse(&version.num).ok()?;
+                Some((parsed, version))
+            })
+            .collect::<Vec<_>>();
+
+        // Oldest versions come first, so the history reads as a timeline
+        published.sort_by(|(first, _), (second, _)| first.cmp(second));
+
+        // Trusted publishing gets answered from this very history, including downgrades,
+        // hence versions outside the requirement still count as previous releases
+        let mut previous_release = None::<(Version, TrustPubData)>;
+        let mut versions = vec![];
+
+        for (parsed, version) in published {
+            let cargo_package = CargoPackage::with(crate_name, &version.num);
+            let trusted_publishing = trusted_publishing_check(
+                &cargo_package,
+                version.trustpub_data.clone(),
+                version.publisher(),
+                previous_release.clone(),
+            )?;
+
+            if let Some(trustpub_data) = version.trustpub_data {
+                previous_release = Some((parsed.clone(), trustpub_data));
+            }
+
+            if requirement.is_none_or(|required| required.matches(&parsed)) {
+                versions.push(PublishedVersion {
+                    cargo_package,
+                    published_at: version.created_at,
+                    yanked: version.yanked,
+                    trusted_publishing,
+                });
+            }
+        }
+
+        Ok(versions)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core::interfaces::VeracityFactorCheck;
-    use crate::core::models::CargoPackage;
-    use crate::infra::networking::crates::OfficialCratesRegistryChecker;
+    use crate::core::models::{
+        CargoPackage, CrateReference, CratesPublisher, FactorCheck, FactorEvidence, FactorStatus, PublishingProvider,
+        RateLimits, RequestedVersion, TrustedPublishingEvidence, VersionSelection,
+    };
     use crate::infra::networking::crates::registry::CratesDotIOClient;
-    use crate::infra::networking::http::{HTTP_CLIENT, MAX_HTTP_RETRY_ATTEMPTS};
-    use assertor::{OptionAssertion, ResultAssertion, StringAssertion};
+    use crate::infra::networking::crates::sparse::SparseIndexClient;
+    use crate::infra::networking::crates::{
+        CrateVersionResolver, CratesRegistryMetadata, OfficialCratesRegistryChecker,
+    };
+    use crate::infra::networking::http::{HTTP_CLIENT, HTTPClient, MAX_HTTP_RETRY_ATTEMPTS, rate_limited_http_client};
+    use crate::infra::networking::ratelimiting::RateLimiter;
+    use assertor::{EqualityAssertion, OptionAssertion, ResultAssertion, StringAssertion};
     use httpmock::{MockServer, Then, When};
+    use semver::VersionReq;
+    use std::sync::Arc;
+    use temp_dir::TempDir;
+
+    fn relaxed_http_client() -> Arc<HTTPClient> {
+        let rate_limits = RateLimits {
+            requests_per_second: 100.0,
+            burst: 1,
+        };
+
+        rate_limited_http_client(Arc::new(RateLimiter::new(&rate_limits)))
+    }
+
+    fn version_resolver(mock_server: &MockServer, cache_dir: &TempDir) -> CrateVersionResolver {
+        let sparse_index = SparseIndexClient::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            cache_dir.path().to_path_buf(),
+        );
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+
+        CrateVersionResolver::new(sparse_index, cratesio_client)
+    }
 
-    static SMALL_DELAY_FOR_RATE_LIMITING: u64 = 10;
+    fn trusted_publishing_evidence(check: FactorCheck<FactorEvidence>) -> TrustedPublishingEvidence {
+        match check.evidence {
+            Some(FactorEvidence::TrustedPublishing(evidence)) => evidence,
+            _ => panic!("expecting trusted publishing evidence"),
+        }
+    }
 
     fn responds_with_existing_trusted_publishing(crate_name: &str, crate_version: &str) -> impl FnOnce(When, Then) {
         move |when, then| {
-            let crate_version_template = r#"
+            let crate_versions_template = r#"
                     {
-                      "version": {
-                        "id": 1711352,
-                        "crate": "<CRATE_NAME>",
-                        "num": "<CRATE_VERSION>",
-                        "trustpub_data": {
-                          "provider": "github",
-                          "repository": "elastio/bon",
-                          "run_id": "17402178810",
-                          "sha": "bbd8b099ea52bf4de18051d012c8113cf0dca23a"
+                      "versions": [
+                        {
+                          "id": 1711352,
+                          "crate": "<CRATE_NAME>",
+                          "num": "<CRATE_VERSION>",
+                          "trustpub_data": {
+                            "provider": "github",
+                            "repository": "elastio/bon",
+                            "run_id": "17402178810",
+                            "sha": "bbd8b099ea52bf4de18051d012c8113cf0dca23a"
+                          }
                         }
-                      }
+                      ]
                     }
                 "#;
 
-            let payload = crate_version_template
+            let payload = crate_versions_template
                 .replace("<CRATE_NAME>", crate_name)
                 .replace("<CRATE_VERSION>", crate_version);
 
             when.method("GET")
-                .path(format!("/api/v1/crates/{}/{}", crate_name, crate_version));
+                .path(format!("/api/v1/crates/{}/versions", crate_name));
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(payload);
+        }
+    }
+
+    fn responds_with_gitlab_trusted_publishing(crate_name: &str, crate_version: &str) -> impl FnOnce(When, Then) {
+        move |when, then| {
+            let crate_versions_template = r#"
+                    {
+                      "versions": [
+                        {
+                          "id": 1811352,
+                          "crate": "<CRATE_NAME>",
+                          "num": "<CRATE_VERSION>",
+                          "trustpub_data": {
+                            "provider": "gitlab",
+                            "project_path": "fake-group/fake-project",
+                            "job_id": "11223344556",
+                            "sha": "0c6a0b0bd2ae4aa95bc1ab2f1f5e8e1e6e2c5d11"
+                          }
+                        }
+                      ]
+                    }
+                "#;
+
+            let payload = crate_versions_template
+                .replace("<CRATE_NAME>", crate_name)
+                .replace("<CRATE_VERSION>", crate_version);
+
+            when.method("GET")
+                .path(format!("/api/v1/crates/{}/versions", crate_name));
 
             then.status(200)
                 .header("content-type", "application/json; charset=UTF-8")
@@ -150,10 +512,105 @@ mod tests {
         }
     }
 
-    fn responds_without_server_error(crate_name: &str, crate_version: &str) -> impl FnOnce(When, Then) {
+    fn responds_with_user_token_publishing(crate_name: &str, crate_version: &str) -> impl FnOnce(When, Then) {
         move |when, then| {
+            let crate_versions_template = r#"
+                    {
+                      "versions": [
+                        {
+                          "id": 1711352,
+                          "crate": "<CRATE_NAME>",
+                          "num": "<CRATE_VERSION>",
+                          "trustpub_data": null,
+                          "published_by": null,
+                          "audit_actions": [
+                            {
+                              "action": "publish",
+                              "user": {
+                                "id": 1234,
+                                "login": "ubiratansoares",
+                                "name": "Ubiratan Soares",
+                                "url": "https://github.com/ubiratansoares"
+                              },
+                              "time": "2025-09-01T10:00:00.000000Z"
+                            }
+                          ]
+                        }
+                      ]
+                    }
+                "#;
+
+            let payload = crate_versions_template
+                .replace("<CRATE_NAME>", crate_name)
+                .replace("<CRATE_VERSION>", crate_version);
+
             when.method("GET")
-                .path(format!("/api/v1/crates/{}/{}", crate_name, crate_version));
+                .path(format!("/api/v1/crates/{}/versions", crate_name));
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(payload);
+        }
+    }
+
+    fn responds_with_versions_history(crate_name: &str) -> impl FnOnce(When, Then) {
+        move |when, then| {
+            let payload = r#"
+                    {
+                      "versions": [
+                        { "num": "0.2.0", "trustpub_data": null },
+                        {
+                          "num": "0.1.2",
+                          "trustpub_data": {
+                            "provider": "github",
+                            "repository": "fake-org/fake-crate",
+                            "run_id": "17402178999",
+                            "sha": "5f1e0bd2ae4aa95bc1ab2f1f5e8e1e6e2c5d1122"
+                          }
+                        },
+                        {
+                          "num": "0.1.1",
+                          "trustpub_data": {
+                            "provider": "github",
+                            "repository": "fake-org/fake-crate",
+                            "run_id": "17402178810",
+                            "sha": "bbd8b099ea52bf4de18051d012c8113cf0dca23a"
+                          }
+                        },
+                        { "num": "0.1.0", "trustpub_data": null }
+                      ],
+                      "meta": { "total": 4, "next_page": null }
+                    }
+                "#;
+
+            when.method("GET")
+                .path(format!("/api/v1/crates/{}/versions", crate_name));
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .body(payload);
+        }
+    }
+
+    fn responds_with_sparse_index_file(crate_name: &str) -> impl FnOnce(When, Then) {
+        move |when, then| {
+            let index_file_template = r#"{"name":"<CRATE_NAME>","vers":"1.0.227","deps":[],"cksum":"80ece43fc6fbed4eb5392ab50c07334d3e577cbf40997ee896fe7af40bba4245","features":{},"yanked":false}
+{"name":"<CRATE_NAME>","vers":"1.0.228","deps":[],"cksum":"9a8e94ea7f378bd32cbbd37198a4a91436180c5bb472411e48b5ec2e2124ae9e","features":{},"yanked":false}
+{"name":"<CRATE_NAME>","vers":"2.0.0-alpha.1","deps":[],"cksum":"6f4ae3b16a9e2b5d4ea4e8fd2c44d9d3c3b8b3f5e0f4a1e2f3c4d5e6f7a8b9c0","features":{},"yanked":false}
+{"name":"<CRATE_NAME>","vers":"2.0.0","deps":[],"cksum":"0c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8a9b0c1d","features":{},"yanked":true}
+"#;
+
+            let payload = index_file_template.replace("<CRATE_NAME>", crate_name);
+
+            when.method("GET").path(format!("/se/rd/{}", crate_name));
+            then.status(200).body(payload);
+        }
+    }
+
+    fn responds_without_server_error(crate_name: &str) -> impl FnOnce(When, Then) {
+        move |when, then| {
+            when.method("GET")
+                .path(format!("/api/v1/crates/{}/versions", crate_name));
 
             then.status(503)
                 .header("content-type", "application/text; charset=UTF-8")
@@ -168,13 +625,9 @@ mod tests {
         let crate_info = CargoPackage::with(crate_name, crate_version);
 
         let mock_server = MockServer::start();
-        let cratesio_client = CratesDotIOClient::new(
-            mock_server.base_url(),
-            HTTP_CLIENT.clone(),
-            SMALL_DELAY_FOR_RATE_LIMITING,
-        );
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
 
-        let checker = OfficialCratesRegistryChecker::new(cratesio_client);
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
 
         let with_trusted_publishing = responds_with_existing_trusted_publishing(crate_name, crate_version);
         let mocked = mock_server.mock(with_trusted_publishing);
@@ -186,31 +639,102 @@ mod tests {
         let expected_path = "elastio/bon/actions/runs/17402178810";
 
         mocked.assert();
-        assertor::assert_that!(check.unwrap().path()).contains(expected_path);
+        let evidence = trusted_publishing_evidence(check);
+        assertor::assert_that!(evidence.workflow_run.path()).contains(expected_path);
+        assertor::assert_that!(evidence.provider).is_equal_to(PublishingProvider::GitHub);
+        assertor::assert_that!(evidence.commit_sha).is_equal_to("bbd8b099ea52bf4de18051d012c8113cf0dca23a".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_check_crate_trusted_publishing_from_gitlab() {
+        let crate_name = "fake-crate";
+        let crate_version = "0.4.2";
+        let crate_info = CargoPackage::with(crate_name, crate_version);
+
+        let mock_server = MockServer::start();
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
+
+        let with_trusted_publishing = responds_with_gitlab_trusted_publishing(crate_name, crate_version);
+        let mocked = mock_server.mock(with_trusted_publishing);
+
+        let check = checker.execute(&crate_info).await.unwrap();
+
+        mocked.assert();
+        let evidence = trusted_publishing_evidence(check);
+        let expected_run = "https://gitlab.com/fake-group/fake-project/-/jobs/11223344556";
+        assertor::assert_that!(evidence.workflow_run.to_string()).is_equal_to(expected_run.to_string());
+        assertor::assert_that!(evidence.provider).is_equal_to(PublishingProvider::GitLab);
+        assertor::assert_that!(evidence.repository).is_equal_to("fake-group/fake-project".to_string());
     }
 
     #[tokio::test]
     async fn should_check_crate_trusted_publishing_when_not_available() {
         let crate_name = "canopus";
-        let crate_version = "0.1.1";
+        let crate_version = "0.1.0";
         let crate_info = CargoPackage::with(crate_name, crate_version);
 
         let mock_server = MockServer::start();
-        let cratesio_client = CratesDotIOClient::new(
-            mock_server.base_url(),
-            HTTP_CLIENT.clone(),
-            SMALL_DELAY_FOR_RATE_LIMITING,
-        );
-        let checker = OfficialCratesRegistryChecker::new(cratesio_client);
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
+
+        let mocked = mock_server.mock(responds_with_versions_history(crate_name));
+
+        let check = checker.execute(&crate_info).await.unwrap();
+
+        mocked.assert();
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Missing);
+        assertor::assert_that!(check.evidence).is_none()
+    }
 
-        let without_trusted_publishing = responds_without_trusted_publishing(crate_name, crate_version);
+    #[tokio::test]
+    async fn should_check_versions_missing_from_history_on_their_own() {
+        let crate_name = "fake-crate";
+        let crate_version = "0.3.0";
+        let crate_info = CargoPackage::with(crate_name, crate_version);
+
+        let mock_server = MockServer::start();
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
 
-        let mocked = mock_server.mock(without_trusted_publishing);
+        let history = mock_server.mock(responds_with_versions_history(crate_name));
+        let details = mock_server.mock(responds_without_trusted_publishing(crate_name, crate_version));
+
+        let check = checker.execute(&crate_info).await.unwrap();
+
+        history.assert();
+        details.assert();
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Mismatch);
+    }
+
+    #[tokio::test]
+    async fn should_report_publisher_when_published_with_user_token() {
+        let crate_name = "canopus";
+        let crate_version = "0.1.1";
+        let crate_info = CargoPackage::with(crate_name, crate_version);
+
+        let mock_server = MockServer::start();
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
+
+        let mocked = mock_server.mock(responds_with_user_token_publishing(crate_name, crate_version));
 
         let check = checker.execute(&crate_info).await.unwrap();
 
         mocked.assert();
-        assertor::assert_that!(check).is_none()
+
+        let expected_publisher = CratesPublisher {
+            login: "ubiratansoares".to_string(),
+            name: Some("Ubiratan Soares".to_string()),
+        };
+
+        let Some(FactorEvidence::UserTokenPublishing(evidence)) = check.evidence else {
+            panic!("expecting user token publishing evidence");
+        };
+
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Missing);
+        assertor::assert_that!(evidence.published_by).is_equal_to(expected_publisher);
     }
 
     #[tokio::test]
@@ -220,14 +744,10 @@ mod tests {
         let crate_info = CargoPackage::with(crate_name, crate_version);
 
         let mock_server = MockServer::start();
-        let cratesio_client = CratesDotIOClient::new(
-            mock_server.base_url(),
-            HTTP_CLIENT.clone(),
-            SMALL_DELAY_FOR_RATE_LIMITING,
-        );
-        let checker = OfficialCratesRegistryChecker::new(cratesio_client);
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
 
-        let not_found = responds_without_server_error(crate_name, crate_version);
+        let not_found = responds_without_server_error(crate_name);
         let mocked = mock_server.mock(not_found);
 
         let check = checker.execute(&crate_info).await;
@@ -235,4 +755,150 @@ mod tests {
         mocked.assert_calls(MAX_HTTP_RETRY_ATTEMPTS as usize + 1);
         assertor::assert_that!(check).is_err()
     }
+
+    #[tokio::test]
+    async fn should_detect_trusted_publishing_downgrade_from_versions_history() {
+        let crate_name = "fake-crate";
+        let crate_info = CargoPackage::with(crate_name, "0.2.0");
+
+        let mock_server = MockServer::start();
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
+
+        let mocked = mock_server.mock(responds_with_versions_history(crate_name));
+
+        let check = checker.execute(&crate_info).await.unwrap();
+
+        mocked.assert();
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Mismatch);
+
+        let Some(FactorEvidence::PublishingDowngrade(downgrade)) = check.evidence else {
+            panic!("expecting a trusted publishing downgrade");
+        };
+
+        assertor::assert_that!(downgrade.previous_version).is_equal_to("0.1.2".to_string());
+        assertor::assert_that!(downgrade.previous_publishing.commit_sha)
+            .is_equal_to("5f1e0bd2ae4aa95bc1ab2f1f5e8e1e6e2c5d1122".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_not_detect_downgrade_without_earlier_trusted_publishing() {
+        let crate_name = "fake-crate";
+        let crate_info = CargoPackage::with(crate_name, "0.1.0");
+
+        let mock_server = MockServer::start();
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
+
+        let mocked = mock_server.mock(responds_with_versions_history(crate_name));
+
+        let check = checker.execute(&crate_info).await.unwrap();
+
+        mocked.assert();
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Missing);
+    }
+
+    #[tokio::test]
+    async fn should_fetch_versions_history_once_per_crate() {
+        let crate_name = "fake-crate";
+
+        let mock_server = MockServer::start();
+        let cratesio_client = CratesDotIOClient::new(mock_server.base_url(), relaxed_http_client());
+        let checker = OfficialCratesRegistryChecker::new(CratesRegistryMetadata::Api(cratesio_client));
+
+        let mocked = mock_server.mock(responds_with_versions_history(crate_name));
+
+        let mut statuses = vec![];
+
+        for crate_version in ["0.1.0", "0.1.1", "0.1.2", "0.2.0"] {
+            let crate_info = CargoPackage::with(crate_name, crate_version);
+            let check = checker.execute(&crate_info).await.unwrap();
+            statuses.push(check.status);
+        }
+
+        mocked.assert_calls(1);
+        assertor::assert_that!(statuses).is_equal_to(vec![
+            FactorStatus::Missing,
+            FactorStatus::Verified,
+            FactorStatus::Verified,
+            FactorStatus::Mismatch,
+        ]);
+    }
+
+    #[tokio::test]
+    async fn should_resolve_versionless_crates_according_to_selection() {
+        let crate_name = "serde";
+
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+        let resolver = version_resolver(&mock_server, &cache_dir);
+
+        let mocked = mock_server.mock(responds_with_sparse_index_file(crate_name));
+
+        let selected = |selection| CrateReference {
+            name: crate_name.to_string(),
+            version: RequestedVersion::Selected(selection),
+        };
+
+        let default_version = resolver.resolve(selected(VersionSelection::Default)).await.unwrap();
+        let latest_stable = resolver
+            .resolve(selected(VersionSelection::LatestStable))
+            .await
+            .unwrap();
+        let latest = resolver.resolve(selected(VersionSelection::Latest)).await.unwrap();
+
+        // Yanked 2.0.0 is never selected
+        mocked.assert_calls(3);
+        assertor::assert_that!(default_version).is_equal_to(CargoPackage::with(crate_name, "1.0.228"));
+        assertor::assert_that!(latest_stable).is_equal_to(CargoPackage::with(crate_name, "1.0.228"));
+        assertor::assert_that!(latest).is_equal_to(CargoPackage::with(crate_name, "2.0.0-alpha.1"));
+    }
+
+    #[tokio::test]
+    async fn should_check_exact_versions_against_sparse_index() {
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+        let resolver = version_resolver(&mock_server, &cache_dir);
+
+        let mocked = mock_server.mock(responds_with_sparse_index_file("serde"));
+
+        let exact = |version: &str| CrateReference {
+            name: "serde".to_string(),
+            version: RequestedVersion::Exact(version.to_string()),
+        };
+
+        let resolved = resolver.resolve(exact("1.0.227")).await.unwrap();
+        let never_published = resolver.resolve(exact("1.0.200")).await;
+
+        mocked.assert_calls(2);
+        assertor::assert_that!(resolved).is_equal_to(CargoPackage::with("serde", "1.0.227"));
+        assertor::assert_that!(never_published).is_err();
+    }
+
+    #[tokio::test]
+    async fn should_list_published_versions_matching_requirement() {
+        let crate_name = "fake-crate";
+
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+        let resolver = version_resolver(&mock_server, &cache_dir);
+
+        let mocked = mock_server.mock(responds_with_versions_history(crate_name));
+
+        let requirement = VersionReq::parse(">=0.1.1").unwrap();
+        let published = resolver
+            .published_versions(crate_name, Some(&requirement))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|version| (version.cargo_package.version, version.trusted_publishing.status))
+            .collect::<Vec<_>>();
+
+        mocked.assert();
+        assertor::assert_that!(published).is_equal_to(vec![
+            ("0.1.1".to_string(), FactorStatus::Verified),
+            ("0.1.2".to_string(), FactorStatus::Verified),
+            ("0.2.0".to_string(), FactorStatus::Mismatch),
+        ]);
+    }
 }
@@ -0,0 +1,456 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::DatabaseDumpLocation;
+use crate::infra::caching::CacheManager;
+use crate::infra::networking::crates::registry::{
+    CrateVersionDetails, CrateVersionsHistory, CratesUser, InfoForCrateVersion, PublishedCrateVersion, TrustPubData,
+};
+use crate::infra::networking::http::HTTPClient;
+use anyhow::{Context, bail};
+use flate2::read::GzDecoder;
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::OnceCell;
+
+pub static URL_CRATESIO_DATABASE_DUMP: &str = "https://static.crates.io/db-dump.tar.gz";
+
+static DATABASE_DUMP_FILE_NAME: &str = "db-dump.tar.gz";
+static DATABASE_DUMP_INDEX_FILE_NAME: &str = "index.json";
+
+// crates.io refreshes its database dump once a day
+static DATABASE_DUMP_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+// Columns we care about, out of the CSV files shipped within the dump
+// See https://crates.io/data-access#database-dumps
+#[derive(Debug, Deserialize)]
+struct DumpedCrate {
+    id: u64,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DumpedVersion {
+    crate_id: u64,
+    num: String,
+    published_by: Option<u64>,
+    trustpub_data: Option<String>,
+    created_at: Option<String>,
+    // Exported by PostgreSQL as 't' or 'f'
+    yanked: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DumpedUser {
+    id: u64,
+    gh_login: String,
+    name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexedVersion {
+    num: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    trustpub_data: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    published_by: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<String>,
+    // Required on purpose, so indexes saved before yanked flags were tracked get rebuilt
+    yanked: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexedUser {
+    login: String,
+    name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseDumpIndex {
+    fingerprint: String,
+    versions: HashMap<String, Vec<IndexedVersion>>,
+    users: HashMap<u64, IndexedUser>,
+}
+
+impl DatabaseDumpIndex {
+    pub fn version_details(&self, crate_name: &str, crate_version: &str) -> anyhow::Result<CrateVersionDetails> {
+        let Some(indexed) = self
+            .versions
+            .get(crate_name)
+            .and_then(|versions| versions.iter().find(|version| version.num == crate_version))
+        else {
+            bail!(
+                "pollux.dbdump : {}@{} not found within crates.io database dump",
+                crate_name,
+                crate_version
+            )
+        };
+
+        let details = CrateVersionDetails {
+            version: InfoForCrateVersion {
+                trustpub_data: parse_trustpub_data(indexed)?,
+                published_by: self.publisher_of(indexed),
+                audit_actions: vec![],
+            },
+        };
+
+        Ok(details)
+    }
+
+    pub fn versions_history(&self, crate_name: &str) -> anyhow::Result<CrateVersionsHistory> {
+        let Some(indexed_versions) = self.versions.get(crate_name) else {
+            bail!(
+                "pollux.dbdump : {} not found within crates.io database dump",
+                crate_name
+            )
+        };
+
+        let versions = indexed_versions
+            .iter()
+            .map(|indexed| {
+                let published = PublishedCrateVersion {
+                    num: indexed.num.clone(),
+                    trustpub_data: parse_trustpub_data(indexed)?,
+                    published_by: self.publisher_of(indexed),
+                    audit_actions: vec![],
+                    created_at: indexed.created_at.clone(),
+                    yanked: indexed.yanked,
+                };
+                Ok(published)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(CrateVersionsHistory { versions })
+    }
+
+    fn publisher_of(&self, indexed: &IndexedVersion) -> Option<CratesUser> {
+        indexed
+            .published_by
+            .and_then(|user_id| self.users.get(&user_id))
+            .map(|user| CratesUser {
+                login: user.login.clone(),
+                name: user.name.clone(),
+            })
+    }
+}
+
+fn parse_trustpub_data(indexed: &IndexedVersion) -> anyhow::Result<Option<TrustPubData>> {
+    indexed
+        .trustpub_data
+        .as_deref()
+        .map(serde_json::from_str::<TrustPubData>)
+        .transpose()
+        .context("pollux.dbdump : invalid trusted publishing data")
+}
+
+// Answers crates.io queries from a local copy of its database, without touching the API
+pub struct CratesDatabaseDump {
+    location: DatabaseDumpLocation,
+    http_client: Arc<HTTPClient>,
+    cache_manager: CacheManager,
+    index: OnceCell<DatabaseDumpIndex>,
+}
+
+impl CratesDatabaseDump {
+    pub fn new(location: DatabaseDumpLocation, http_client: Arc<HTTPClient>, cache_manager: CacheManager) -> Self {
+        Self {
+            location,
+            http_client,
+            cache_manager,
+            index: OnceCell::new(),
+        }
+    }
+
+    pub async fn get_crate_version_details(
+        &self,
+        crate_name: &str,
+        crate_version: &str,
+    ) -> anyhow::Result<CrateVersionDetails> {
+        self.index().await?.version_details(crate_name, crate_version)
+    }
+
+    pub async fn get_crate_versions_history(&self, crate_name: &str) -> anyhow::Result<CrateVersionsHistory> {
+        self.index().await?.versions_history(crate_name)
+    }
+
+    async fn index(&self) -> anyhow::Result<&DatabaseDumpIndex> {
+        self.index.get_or_try_init(|| self.load_index()).await
+    }
+
+    async fn load_index(&self) -> anyhow::Result<DatabaseDumpIndex> {
+        let dump_file = match &self.location {
+            DatabaseDumpLocation::Latest => self.download_latest().await?,
+            DatabaseDumpLocation::LocalFile(path) => path.clone(),
+        };
+
+        let fingerprint = fingerprint(&dump_file)?;
+        let index_file = self
+            .cache_manager
+            .database_dump_dir()
+            .join(DATABASE_DUMP_INDEX_FILE_NAME);
+
+        if let Ok(serialized) = fs::read(&index_file)
+            && let Ok(index) = serde_json::from_slice::<DatabaseDumpIndex>(&serialized)
+            && index.fingerprint == fingerprint
+        {
+            log::info!("[pollux.dbdump] reusing index at {:?}", index_file);
+            return Ok(index);
+        }
+
+        log::info!("[pollux.dbdump] indexing database dump at {:?}", dump_file);
+
+        // Decompressing and parsing the whole dump is CPU-bound and takes a while
+        let index = tokio::task::spawn_blocking(move || {
+            let archive = File::open(&dump_file)
+                .with_context(|| format!("pollux.dbdump : cannot open database dump ({:?})", dump_file))?;
+            index_database_dump(GzDecoder::new(BufReader::new(archive)), fingerprint)
+        })
+        .await??;
+
+        fs::create_dir_all(self.cache_manager.database_dump_dir())?;
+        fs::write(&index_file, serde_json::to_vec(&index)?).context("pollux.dbdump : cannot save index")?;
+
+        log::info!("[pollux.dbdump] saved index at {:?}", index_file);
+        Ok(index)
+    }
+
+    async fn download_latest(&self) -> anyhow::Result<PathBuf> {
+        let dump_dir = self.cache_manager.database_dump_dir();
+        let dump_file = dump_dir.join(DATABASE_DUMP_FILE_NAME);
+
+        let age = fs::metadata(&dump_file)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+
+        if age.is_some_and(|age| age < DATABASE_DUMP_MAX_AGE) {
+            log::info!("[pollux.dbdump] reusing database dump downloaded at {:?}", dump_file);
+            return Ok(dump_file);
+        }
+
+        log::info!(
+            "[pollux.dbdump] downloading database dump from {}",
+            URL_CRATESIO_DATABASE_DUMP
+        );
+        fs::create_dir_all(&dump_dir)?;
+
+        // Downloads land on a separate file, so an interrupted one never looks like a valid dump
+        let partial_file = dump_dir.join(format!("{}.partial", DATABASE_DUMP_FILE_NAME));
+        let mut output = File::create(&partial_file)?;
+
+        let mut response = self
+            .http_client
+            .get(URL_CRATESIO_DATABASE_DUMP)
+            .send()
+            .await?
+            .error_for_status()
+            .context("pollux.dbdump : failed to download database dump")?;
+
+        while let Some(chunk) = response.chunk().await? {
+            output.write_all(&chunk)?;
+        }
+
+        output.flush()?;
+        fs::rename(&partial_file, &dump_file)?;
+
+        log::info!("[pollux.dbdump] downloaded database dump into {:?}", dump_file);
+        Ok(dump_file)
+    }
+}
+
+// Re-indexing only happens when the dump file changes
+fn fingerprint(dump_file: &Path) -> anyhow::Result<String> {
+    let metadata =
+        fs::metadata(dump_file).with_context(|| format!("pollux.dbdump : no database dump at {:?}", dump_file))?;
+    let modified = metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs();
+    let canonical = fs::canonicalize(dump_file)?;
+
+    Ok(format!("{}:{}:{}", canonical.display(), metadata.len(), modified))
+}
+
+// Timestamps are dumped like '2025-09-01 10:00:00.123456+00', unlike the RFC 3339 ones served by the API
+fn normalize_timestamp(dumped: &str) -> Option<String> {
+    dumped.parse::<Timestamp>().ok().map(|timestamp| timestamp.to_string())
+}
+
+fn index_database_dump(archive: impl Read, fingerprint: String) -> anyhow::Result<DatabaseDumpIndex> {
+    let mut crate_names = HashMap::new();
+    let mut versions_by_crate = HashMap::<u64, Vec<IndexedVersion>>::new();
+    let mut users = HashMap::new();
+
+    let mut archive = tar::Archive::new(archive);
+
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let entry_path = entry.path()?.to_path_buf();
+
+        // Entries live under a folder named after the dump timestamp
+        if !entry_path.parent().is_some_and(|parent| parent.ends_with("data")) {
+            continue;
+        }
+
+        let Some(file_name) = entry_path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        let mut csv_reader = csv::Reader::from_reader(entry);
+
+        match file_name {
+            "crates.csv" => {
+                for dumped in csv_reader.deserialize::<DumpedCrate>() {
+                    let dumped = dumped.context("pollux.dbdump : invalid crates.csv")?;
+                    crate_names.insert(dumped.id, dumped.name);
+                }
+            },
+            "versions.csv" => {
+                for dumped in csv_reader.deserialize::<DumpedVersion>() {
+                    let dumped = dumped.context("pollux.dbdump : invalid versions.csv")?;
+                    let indexed = IndexedVersion {
+                        num: dumped.num,
+                        trustpub_data: dumped.trustpub_data.filter(|data| !data.is_empty()),
+                        published_by: dumped.published_by,
+                        created_at: dumped.created_at.as_deref().and_then(normalize_timestamp),
+                        yanked: dumped.yanked == "t",
+                    };
+                    versions_by_crate.entry(dumped.crate_id).or_default().push(indexed);
+                }
+            },
+            "users.csv" => {
+                for dumped in csv_reader.deserialize::<DumpedUser>() {
+                    let dumped = dumped.context("pollux.dbdump : invalid users.csv")?;
+                    let indexed = IndexedUser {
+                        login: dumped.gh_login,
+                        name: dumped.name.filter(|name| !name.is_empty()),
+                    };
+                    users.insert(dumped.id, indexed);
+                }
+            },
+            _ => continue,
+        }
+    }
+
+    if crate_names.is_empty() || versions_by_crate.is_empty() {
+        bail!("pollux.dbdump : crates or versions missing from database dump")
+    }
+
+    let versions = versions_by_crate
+        .into_iter()
+        .filter_map(|(crate_id, versions)| Some((crate_names.remove(&crate_id)?, versions)))
+        .collect::<HashMap<_, _>>();
+
+    // Only publishers are worth keeping around
+    let publishers = versions
+        .values()
+        .flatten()
+        .filter_map(|version| version.published_by)
+        .collect::<HashSet<_>>();
+
+    users.retain(|user_id, _| publishers.contains(user_id));
+
+    let index = DatabaseDumpIndex {
+        fingerprint,
+        versions,
+        users,
+    };
+
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infra::networking::crates::dbdump::index_database_dump;
+    use crate::infra::networking::crates::registry::TrustPubData;
+    use assertor::{BooleanAssertion, EqualityAssertion};
+
+    fn fake_database_dump() -> Vec<u8> {
+        let crates = "id,name,description\n1,bon,Builders\n2,serde,Serialization\n";
+        let versions = "id,crate_id,num,published_by,trustpub_data,created_at,yanked\n\
+            10,1,3.7.1,100,,2025-09-01 10:00:00.123456+00,t\n\
+            11,1,3.7.2,,\"{\"\"provider\"\":\"\"github\"\",\"\"repository\"\":\"\"elastio/bon\"\",\"\"run_id\"\":\"\"17402178810\"\",\"\"sha\"\":\"\"0a1b2c3\"\"}\",2025-09-15 08:30:00+00,f\n\
+            20,2,1.0.228,200,,2025-09-27 18:00:00+00,f\n";
+        let users = "id,gh_login,name\n100,Veetaha,Vitalii\n200,dtolnay,\n300,someone,Someone Else\n";
+
+        let mut builder = tar::Builder::new(vec![]);
+
+        for (file_name, contents) in [
+            ("2025-10-17-020003/data/crates.csv", crates),
+            ("2025-10-17-020003/data/versions.csv", versions),
+            ("2025-10-17-020003/data/users.csv", users),
+            ("2025-10-17-020003/README.md", "Database dump"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, file_name, contents.as_bytes())
+                .expect("failed to append file");
+        }
+
+        builder.into_inner().expect("failed to build archive")
+    }
+
+    #[test]
+    fn should_answer_version_details_from_database_dump() {
+        let index = index_database_dump(fake_database_dump().as_slice(), "fake".to_string()).unwrap();
+
+        let trusted = index.version_details("bon", "3.7.2").unwrap();
+        let with_token = index.version_details("serde", "1.0.228").unwrap();
+
+        let trustpub_data = trusted.version.trustpub_data;
+        let publisher = with_token.version.published_by.map(|user| user.login);
+
+        assertor::assert_that!(matches!(trustpub_data, Some(TrustPubData::GitHub { .. }))).is_true();
+        assertor::assert_that!(publisher).is_equal_to(Some("dtolnay".to_string()));
+        assertor::assert_that!(index.users.len()).is_equal_to(2);
+        assertor::assert_that!(index.version_details("serde", "0.9.0").is_err()).is_true();
+    }
+
+    #[test]
+    fn should_answer_versions_history_from_database_dump() {
+        let index = index_database_dump(fake_database_dump().as_slice(), "fake".to_string()).unwrap();
+
+        let history = index.versions_history("bon").unwrap();
+        let trusted_versions = history
+            .versions
+            .into_iter()
+            .filter(|version| version.trustpub_data.is_some())
+            .map(|version| version.num)
+            .collect::<Vec<_>>();
+
+        assertor::assert_that!(trusted_versions).is_equal_to(vec!["3.7.2".to_string()]);
+        assertor::assert_that!(index.versions_history("tokio").is_err()).is_true();
+    }
+
+    #[test]
+    fn should_keep_yanked_flags_and_publishing_dates_from_database_dump() {
+        let index = index_database_dump(fake_database_dump().as_slice(), "fake".to_string()).unwrap();
+
+        let history = index
+            .versions_history("bon")
+            .unwrap()
+            .versions
+            .into_iter()
+            .map(|version| (version.num, version.created_at, version.yanked))
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            (
+                "3.7.1".to_string(),
+                Some("2025-09-01T10:00:00.123456Z".to_string()),
+                true,
+            ),
+            ("3.7.2".to_string(), Some("2025-09-15T08:30:00Z".to_string()), false),
+        ];
+
+        assertor::assert_that!(history).is_equal_to(expected);
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::interfaces::NamedFactorCheck;
+use crate::core::models::{
+    CargoPackage, CrateVeracityChecks, FactorCheck, FactorEvidence, FactorStatus, RepositoryConsistencyEvidence,
+    TrustedPublishingEvidence, VcsProvenanceEvidence, VeracityFactor,
+};
+use crate::infra::networking::crates::tarballs::CrateArchiveDownloader;
+use anyhow::Context;
+use camino::Utf8Path;
+use serde::Deserialize;
+use std::fs;
+
+static CARGO_MANIFEST_FILE: &str = "Cargo.toml";
+static CARGO_VCS_INFO_FILE: &str = ".cargo_vcs_info.json";
+
+// See https://doc.rust-lang.org/cargo/commands/cargo-package.html#cargo_vcs_infojson-format
+#[derive(Debug, PartialEq, Deserialize)]
+struct CargoVcsInfo {
+    git: GitVcsInfo,
+    path_in_vcs: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct GitVcsInfo {
+    sha1: String,
+    #[serde(default)]
+    dirty: bool,
+}
+
+pub struct RepositoryConsistencyChecker {
+    crate_downloader: CrateArchiveDownloader,
+}
+
+impl RepositoryConsistencyChecker {
+    pub fn new(crate_downloader: CrateArchiveDownloader) -> Self {
+        Self { crate_downloader }
+    }
+}
+
+impl NamedFactorCheck for RepositoryConsistencyChecker {
+    fn factor(&self) -> VeracityFactor {
+        VeracityFactor::RepositoryConsistency
+    }
+
+    async fn execute(
+        &self,
+        crate_info: &CargoPackage,
+        collected: &CrateVeracityChecks,
+    ) -> anyhow::Result<FactorCheck<FactorEvidence>> {
+        let Some(publishing) = collected.trusted_publishing() else {
+            log::info!(
+                "[pollux.checker] no trusted publishing to cross-check repository for {}",
+                crate_info
+            );
+            return Ok(FactorCheck::missing());
+        };
+
+        let sources_dir = self.crate_downloader.cached_sources(crate_info).await?;
+        let declared_repository = read_declared_repository(&sources_dir)?;
+        let path_in_vcs = read_vcs_info(&sources_dir)?.and_then(|vcs_info| vcs_info.path_in_vcs);
+        let check = evaluate_repository_consistency(publishing, declared_repository, path_in_vcs);
+        Ok(check.map(FactorEvidence::RepositoryConsistency))
+    }
+}
+
+fn read_declared_repository(sources_dir: &Utf8Path) -> anyhow::Result<Option<String>> {
+    let manifest_path = sources_dir.join(CARGO_MANIFEST_FILE);
+    let contents = fs::read_to_string(&manifest_path).context("pollux.checker : cannot read crate manifest")?;
+    let manifest: toml::Table = toml::from_str(&contents).context("pollux.checker : cannot parse crate manifest")?;
+
+    let repository = manifest
+        .get("package")
+        .and_then(|package| package.get("repository"))
+        .and_then(|repository| repository.as_str())
+        .map(|repository| repository.to_string());
+
+    Ok(repository)
+}
+
+fn evaluate_repository_consistency(
+    publishing: &TrustedPublishingEvidence,
+    declared_repository: Option<String>,
+    path_in_vcs: Option<String>,
+) -> FactorCheck<RepositoryConsistencyEvidence> {
+    let status = match &declared_repository {
+        None => FactorStatus::Missing,
+        Some(declared) if publishing.matches_repository(declared, path_in_vcs.as_deref()) => FactorStatus::Verified,
+        Some(_) => FactorStatus::Mismatch,
+    };
+
+    let evidence = RepositoryConsistencyEvidence {
+        declared_repository,
+        publishing_repository: publishing.repository_url(),
+        path_in_vcs,
+    };
+
+    FactorCheck {
+        status,
+        evidence: Some(evidence),
+    }
+}
+
+pub struct VcsProvenanceChecker {
+    crate_downloader: CrateArchiveDownloader,
+}
+
+impl VcsProvenanceChecker {
+    pub fn new(crate_downloader: CrateArchiveDownloader) -> Self {
+        Self { crate_downloader }
+    }
+}
+
+impl NamedFactorCheck for VcsProvenanceChecker {
+    fn factor(&self) -> VeracityFactor {
+        VeracityFactor::VcsProvenance
+    }
+
+    async fn execute(
+        &self,
+        crate_info: &CargoPackage,
+        collected: &CrateVeracityChecks,
+    ) -> anyhow::Result<FactorCheck<FactorEvidence>> {
+        let sources_dir = self.crate_downloader.cached_sources(crate_info).await?;
+
+        let Some(vcs_info) = read_vcs_info(&sources_dir)? else {
+            log::info!("[pollux.checker] no vcs info found for {}", crate_info);
+            return Ok(FactorCheck::missing());
+        };
+
+        let publishing_commit_sha = collected
+            .trusted_publishing()
+            .map(|publishing| publishing.commit_sha.clone());
+
+        let check = evaluate_vcs_provenance(vcs_info, publishing_commit_sha);
+        Ok(check.map(FactorEvidence::VcsProvenance))
+    }
+}
+
+fn read_vcs_info(sources_dir: &Utf8Path) -> anyhow::Result<Option<CargoVcsInfo>> {
+    let vcs_info_path = sources_dir.join(CARGO_VCS_INFO_FILE);
+
+    if !vcs_info_path.exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&vcs_info_path).context("pollux.checker : cannot read vcs info")?;
+    let vcs_info = serde_json::from_str(&contents).context("pollux.checker : cannot parse vcs info")?;
+    Ok(Some(vcs_info))
+}
+
+fn evaluate_vcs_provenance(
+    vcs_info: CargoVcsInfo,
+    publishing_commit_sha: Option<String>,
+) -> FactorCheck<VcsProvenanceEvidence> {
+    let evidence = VcsProvenanceEvidence {
+        commit_sha: vcs_info.git.sha1,
+        path_in_vcs: vcs_info.path_in_vcs,
+        dirty: vcs_info.git.dirty,
+        publishing_commit_sha,
+    };
+
+    // a dirty working tree means the published sources may not exist at any commit
+    if evidence.dirty || evidence.matches_publishing_commit() == Some(false) {
+        return FactorCheck::mismatch(evidence);
+    }
+
+    FactorCheck::verified(evidence)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::interfaces::NamedFactorCheck;
+    use crate::core::models::{
+        CargoPackage, CrateVeracityChecks, FactorStatus, PublishingProvider, TrustedPublishingEvidence,
+    };
+    use crate::infra::caching::CacheManager;
+    use crate::infra::networking::crates::provenance::{
+        CargoVcsInfo, GitVcsInfo, RepositoryConsistencyChecker, evaluate_repository_consistency,
+        evaluate_vcs_provenance, read_declared_repository, read_vcs_info,
+    };
+    use crate::infra::networking::crates::sparse::SparseIndexClient;
+    use crate::infra::networking::crates::tarballs::CrateArchiveDownloader;
+    use crate::infra::networking::http::HTTP_CLIENT;
+    use assertor::{BooleanAssertion, EqualityAssertion, OptionAssertion};
+    use camino::Utf8PathBuf;
+    use httpmock::MockServer;
+    use std::fs;
+    use std::str::FromStr;
+    use temp_dir::TempDir;
+    use url::Url;
+
+    fn published_from(provider: PublishingProvider, repository: &str) -> TrustedPublishingEvidence {
+        TrustedPublishingEvidence {
+            provider,
+            repository: repository.to_string(),
+            workflow_run: Url::from_str("https://shortn.ed/actions/runs/12345789").unwrap(),
+            commit_sha: "bbd8b099ea52bf4de18051d012c8113cf0dca23a".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_skip_repository_consistency_without_collected_trusted_publishing() {
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+
+        let sparse_index = SparseIndexClient::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            cache_dir.path().to_path_buf(),
+        );
+
+        let downloader = CrateArchiveDownloader::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            sparse_index,
+            CacheManager::get(),
+        );
+        let checker = RepositoryConsistencyChecker::new(downloader);
+
+        let mocked = mock_server.mock(|when, then| {
+            when.any_request();
+            then.status(500);
+        });
+
+        let cargo_package = CargoPackage::with("bon", "3.7.2");
+        let check = checker
+            .execute(&cargo_package, &CrateVeracityChecks::default())
+            .await
+            .unwrap();
+
+        mocked.assert_calls(0);
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Missing);
+    }
+
+    #[test]
+    fn should_read_declared_repository_from_manifest() {
+        let manifest_contents = r#"
+            [package]
+            edition = "2021"
+            name = "bon"
+            version = "3.7.2"
+            repository = "https://github.com/elastio/bon"
+        "#;
+
+        let sources = TempDir::new().expect("Cant create temp dir");
+        fs::write(sources.path().join("Cargo.toml"), manifest_contents).expect("failed to write manifest");
+        let sources_dir = Utf8PathBuf::try_from(sources.path().to_path_buf()).unwrap();
+
+        let declared = read_declared_repository(&sources_dir).unwrap();
+
+        assertor::assert_that!(declared).is_equal_to(Some("https://github.com/elastio/bon".to_string()));
+    }
+
+    #[test]
+    fn should_handle_manifest_without_repository() {
+        let manifest_contents = r#"
+            [package]
+            name = "canopus"
+            version = "0.1.1"
+        "#;
+
+        let sources = TempDir::new().expect("Cant create temp dir");
+        fs::write(sources.path().join("Cargo.toml"), manifest_contents).expect("failed to write manifest");
+        let sources_dir = Utf8PathBuf::try_from(sources.path().to_path_buf()).unwrap();
+
+        let declared = read_declared_repository(&sources_dir).unwrap();
+
+        assertor::assert_that!(declared).is_none();
+    }
+
+    #[test]
+    fn should_evaluate_consistent_repositories() {
+        let publishing = published_from(PublishingProvider::GitHub, "elastio/bon");
+
+        let declarations = [
+            "https://github.com/elastio/bon",
+            "https://github.com/Elastio/bon.git",
+            "https://github.com/elastio/bon/",
+            "https://github.com/elastio/bon/tree/master/bon",
+            "https://www.github.com/elastio/bon",
+            "git@github.com:elastio/bon.git",
+        ];
+
+        for declared in declarations {
+            let check = evaluate_repository_consistency(&publishing, Some(declared.to_string()), None);
+            assertor::assert_that!(check.status).is_equal_to(FactorStatus::Verified);
+        }
+    }
+
+    #[test]
+    fn should_evaluate_mismatching_repositories() {
+        let publishing = published_from(PublishingProvider::GitHub, "elastio/bon");
+
+        let declarations = [
+            "https://github.com/elastio/bon-fork",
+            "https://gitlab.com/elastio/bon",
+            "https://github.com/attacker/bon",
+            "https://github.com/elastio/www.bon",
+        ];
+
+        for declared in declarations {
+            let check = evaluate_repository_consistency(&publishing, Some(declared.to_string()), None);
+            assertor::assert_that!(check.status).is_equal_to(FactorStatus::Mismatch);
+        }
+    }
+
+    #[test]
+    fn should_confirm_declared_subfolder_with_path_in_vcs() {
+        let publishing = published_from(PublishingProvider::GitHub, "elastio/bon");
+        let declared = "https://github.com/elastio/bon/tree/master/bon";
+
+        let scenarios = [
+            (Some("bon"), FactorStatus::Verified),
+            (Some(""), FactorStatus::Verified),
+            (Some("bon-macros"), FactorStatus::Mismatch),
+        ];
+
+        for (path_in_vcs, expected) in scenarios {
+            let check = evaluate_repository_consistency(
+                &publishing,
+                Some(declared.to_string()),
+                path_in_vcs.map(|path| path.to_string()),
+            );
+            assertor::assert_that!(check.status).is_equal_to(expected);
+        }
+
+        let check = evaluate_repository_consistency(
+            &publishing,
+            Some("https://github.com/elastio/bon".to_string()),
+            Some("bon-macros".to_string()),
+        );
+
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Verified);
+        assertor::assert_that!(check.evidence.unwrap().path_in_vcs).is_equal_to(Some("bon-macros".to_string()));
+    }
+
+    #[test]
+    fn should_evaluate_undeclared_repository() {
+        let publishing = published_from(PublishingProvider::GitLab, "fake-group/fake-project");
+
+        let check = evaluate_repository_consistency(&publishing, None, None);
+
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Missing);
+        assertor::assert_that!(check.evidence.unwrap().publishing_repository)
+            .is_equal_to("https://gitlab.com/fake-group/fake-project".to_string());
+    }
+
+    #[test]
+    fn should_read_vcs_info_from_sources() {
+        let vcs_info_contents = r#"
+            {
+              "git": {
+                "sha1": "bbd8b099ea52bf4de18051d012c8113cf0dca23a"
+              },
+              "path_in_vcs": "bon"
+            }
+        "#;
+
+        let sources = TempDir::new().expect("Cant create temp dir");
+        fs::write(sources.path().join(".cargo_vcs_info.json"), vcs_info_contents).expect("failed to write vcs info");
+        let sources_dir = Utf8PathBuf::try_from(sources.path().to_path_buf()).unwrap();
+
+        let vcs_info = read_vcs_info(&sources_dir).unwrap().unwrap();
+
+        assertor::assert_that!(vcs_info.git.sha1).is_equal_to("bbd8b099ea52bf4de18051d012c8113cf0dca23a".to_string());
+        assertor::assert_that!(vcs_info.git.dirty).is_false();
+        assertor::assert_that!(vcs_info.path_in_vcs).is_equal_to(Some("bon".to_string()));
+    }
+
+    #[test]
+    fn should_handle_sources_without_vcs_info() {
+        let sources = TempDir::new().expect("Cant create temp dir");
+        let sources_dir = Utf8PathBuf::try_from(sources.path().to_path_buf()).unwrap();
+
+        let vcs_info = read_vcs_info(&sources_dir).unwrap();
+
+        assertor::assert_that!(vcs_info).is_none();
+    }
+
+    #[test]
+    fn should_evaluate_vcs_provenance() {
+        let publishing_sha = "bbd8b099ea52bf4de18051d012c8113cf0dca23a";
+
+        let scenarios = [
+            (publishing_sha, false, Some(publishing_sha), FactorStatus::Verified),
+            (publishing_sha, false, None, FactorStatus::Verified),
+            (publishing_sha, true, Some(publishing_sha), FactorStatus::Mismatch),
+            ("0a1b2c3d4e5f", false, Some(publishing_sha), FactorStatus::Mismatch),
+        ];
+
+        for (vcs_sha, dirty, publishing_commit_sha, expected) in scenarios {
+            let vcs_info = CargoVcsInfo {
+                git: GitVcsInfo {
+                    sha1: vcs_sha.to_string(),
+                    dirty,
+                },
+                path_in_vcs: None,
+            };
+
+            let check = evaluate_vcs_provenance(vcs_info, publishing_commit_sha.map(|sha| sha.to_string()));
+            assertor::assert_that!(check.status).is_equal_to(expected);
+        }
+    }
+}
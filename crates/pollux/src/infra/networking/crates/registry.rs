@@ -2,23 +2,66 @@
 // SPDX-License-Identifier: MIT
 
 use crate::infra::networking::http::HTTPClient;
-use anyhow::Context;
 use serde::Deserialize;
-use std::sync::Arc;
-use std::time::Duration;
-use tokio::time::sleep;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 pub static URL_OFFICIAL_CRATES_REGISTRY: &str = "https://crates.io";
 
-#[derive(Debug, Deserialize)]
-pub struct TrustPubData {
-    pub repository: String,
-    pub run_id: String,
+// See https://crates.io/docs/trusted-publishing
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "provider")]
+pub enum TrustPubData {
+    #[serde(rename = "github")]
+    GitHub {
+        repository: String,
+        run_id: String,
+        sha: String,
+    },
+    #[serde(rename = "gitlab")]
+    GitLab {
+        project_path: String,
+        job_id: String,
+        sha: String,
+    },
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CratesUser {
+    pub login: String,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AuditAction {
+    pub action: String,
+    pub user: Option<CratesUser>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct InfoForCrateVersion {
     pub trustpub_data: Option<TrustPubData>,
+    pub published_by: Option<CratesUser>,
+    #[serde(default)]
+    pub audit_actions: Vec<AuditAction>,
+}
+
+impl InfoForCrateVersion {
+    pub fn publisher(self) -> Option<CratesUser> {
+        publisher_from(self.published_by, self.audit_actions)
+    }
+}
+
+// Older versions may lack published_by, while still recording who published them
+fn publisher_from(published_by: Option<CratesUser>, audit_actions: Vec<AuditAction>) -> Option<CratesUser> {
+    published_by.or_else(|| {
+        audit_actions
+            .into_iter()
+            .filter(|audit| audit.action == "publish")
+            .find_map(|audit| audit.user)
+    })
 }
 
 #[derive(Debug, Deserialize)]
@@ -26,6 +69,31 @@ pub struct CrateVersionDetails {
     pub version: InfoForCrateVersion,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct PublishedCrateVersion {
+    pub num: String,
+    pub trustpub_data: Option<TrustPubData>,
+    #[serde(default)]
+    pub published_by: Option<CratesUser>,
+    #[serde(default)]
+    pub audit_actions: Vec<AuditAction>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub yanked: bool,
+}
+
+impl PublishedCrateVersion {
+    pub fn publisher(&self) -> Option<CratesUser> {
+        publisher_from(self.published_by.clone(), self.audit_actions.clone())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CrateVersionsHistory {
+    pub versions: Vec<PublishedCrateVersion>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PaginatedCratesListing {
     pub crates: Vec<CrateOverview>,
@@ -39,16 +107,19 @@ pub struct CrateOverview {
 
 pub struct CratesDotIOClient {
     base_url: String,
+    // Paced by the crates.io rate limiter, see http::rate_limited_http_client
     http_client: Arc<HTTPClient>,
-    enforced_delay: u64,
+    // A history covers every release of a crate, hence it is fetched once no matter how many
+    // versions of that crate get checked
+    versions_histories: Mutex<HashMap<String, CrateVersionsHistory>>,
 }
 
 impl CratesDotIOClient {
-    pub fn new(base_url: String, http_client: Arc<HTTPClient>, enforced_delay: u64) -> Self {
+    pub fn new(base_url: String, http_client: Arc<HTTPClient>) -> Self {
         Self {
             base_url,
             http_client,
-            enforced_delay,
+            versions_histories: Mutex::new(HashMap::new()),
         }
     }
 
@@ -75,8 +146,6 @@ impl CratesDotIOClient {
         crate_name: &str,
         crate_version: &str,
     ) -> anyhow::Result<CrateVersionDetails> {
-        self.honor_cratesio_rate_limit().await;
-
         let endpoint = format!("{}/api/v1/crates/{}/{}", self.base_url, crate_name, crate_version);
 
         let crates_details = self
@@ -91,26 +160,32 @@ impl CratesDotIOClient {
         Ok(crates_details)
     }
 
-    pub async fn get_crate_tarball(&self, crate_name: &str, crate_version: &str) -> anyhow::Result<bytes::Bytes> {
-        self.honor_cratesio_rate_limit().await;
+    pub async fn get_crate_versions_history(&self, crate_name: &str) -> anyhow::Result<CrateVersionsHistory> {
+        let memoised = self
+            .versions_histories
+            .lock()
+            .ok()
+            .and_then(|histories| histories.get(crate_name).cloned());
 
-        let endpoint = format!(
-            "{}/api/v1/crates/{}/{}/download",
-            self.base_url, crate_name, crate_version
-        );
-        let response = self
+        if let Some(history) = memoised {
+            return Ok(history);
+        }
+
+        let endpoint = format!("{}/api/v1/crates/{}/versions", self.base_url, crate_name);
+
+        let history = self
             .http_client
             .get(&endpoint)
             .send()
             .await?
-            .error_for_status()
-            .context("[pollux.cratesio] failed to download crate tarball")?;
+            .error_for_status()?
+            .json::<CrateVersionsHistory>()
+            .await?;
 
-        let bytes = response.bytes().await?;
-        Ok(bytes)
-    }
+        if let Ok(mut histories) = self.versions_histories.lock() {
+            histories.insert(crate_name.to_string(), history.clone());
+        }
 
-    async fn honor_cratesio_rate_limit(&self) {
-        sleep(Duration::from_millis(self.enforced_delay)).await
+        Ok(history)
     }
 }
@@ -1,31 +1,172 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::CargoPackage;
+pub mod metadata;
+
+use crate::core::graphs::DependencyGraph;
+use crate::core::models::{
+    CargoPackage, DependencyKind, MetadataFilters, PackageSource, ResolvedDependencies, UnverifiablePackage,
+};
+use crate::infra::caching::CacheManager;
+use crate::infra::networking::crates::resolvers::metadata::CargoMetadataDependenciesResolver;
 use crate::infra::networking::crates::tarballs::CrateArchiveDownloader;
-use anyhow::bail;
+use anyhow::{Context, bail};
 use camino::Utf8PathBuf;
-use cargo_lock::Lockfile;
+use cargo_lock::{Lockfile, Name, Package, SourceId, Version};
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf, absolute};
 use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs};
 
 pub struct DependenciesResolver {
     crate_downloader: CrateArchiveDownloader,
+    cache_manager: CacheManager,
+    metadata_filters: Option<MetadataFilters>,
+    locked: bool,
 }
 
 impl DependenciesResolver {
-    pub fn new(crate_downloader: CrateArchiveDownloader) -> Self {
-        Self { crate_downloader }
+    pub fn new(
+        crate_downloader: CrateArchiveDownloader,
+        cache_manager: CacheManager,
+        metadata_filters: Option<MetadataFilters>,
+        locked: bool,
+    ) -> Self {
+        Self {
+            crate_downloader,
+            cache_manager,
+            metadata_filters,
+            locked,
+        }
     }
 
-    pub async fn resolve_for_local_project(&self, project_path: Utf8PathBuf) -> anyhow::Result<Vec<CargoPackage>> {
-        let local_resolver = LocalProjectDependenciesResolver::new(project_path);
-        local_resolver.resolve().await
+    pub async fn resolve_for_local_project(&self, project_path: Utf8PathBuf) -> anyhow::Result<ResolvedDependencies> {
+        if project_path.join("Cargo.lock").exists() {
+            return self.resolve_project(project_path).await;
+        }
+
+        // Workspace members share the lockfile living at the workspace root
+        let lockfile_hint = || {
+            format!(
+                "pollux.cargo : cannot generate a lockfile for {} over a scratch copy, \
+                 run `cargo generate-lockfile` beforehand and pass --locked to rely on it",
+                project_path
+            )
+        };
+
+        let workspace_root = locate_workspace_root(&project_path).with_context(lockfile_hint)?;
+
+        if workspace_root.join("Cargo.lock").exists() {
+            return self.resolve_project(workspace_root).await;
+        }
+
+        if self.locked {
+            bail!(
+                "pollux.cargo : no Cargo.lock found at {} while running in locked mode",
+                workspace_root
+            );
+        }
+
+        // Lockfiles are generated over a scratch copy, so the analysed checkout is never mutated
+        let scratch_root = self
+            .copy_into_scratch_dir(&workspace_root)
+            .with_context(lockfile_hint)?;
+        let resolved = self
+            .resolve_project(scratch_root.clone())
+            .await
+            .with_context(lockfile_hint);
+
+        if let Err(e) = fs::remove_dir_all(&scratch_root) {
+            log::warn!("[pollux.cargo] cannot remove scratch copy at {} : {}", scratch_root, e);
+        }
+
+        resolved
     }
 
-    pub async fn resolve_for_crate_package(&self, cargo_package: &CargoPackage) -> anyhow::Result<Vec<CargoPackage>> {
+    pub async fn resolve_for_crate_package(
+        &self,
+        cargo_package: &CargoPackage,
+    ) -> anyhow::Result<ResolvedDependencies> {
+        // Crates pulling nothing that would be followed resolve to themselves,
+        // hence neither their sources nor a lockfile are needed
+        let followed_kinds = match &self.metadata_filters {
+            Some(filters) => filters.kinds.clone(),
+            None => vec![DependencyKind::Normal, DependencyKind::Build, DependencyKind::Dev],
+        };
+
+        let indexed = self.crate_downloader.indexed_version(cargo_package).await?;
+
+        if indexed
+            .deps
+            .iter()
+            .all(|dependency| !followed_kinds.contains(&dependency.dependency_kind()))
+        {
+            let skipped = indexed
+                .deps
+                .iter()
+                .map(|dependency| dependency.name.as_str())
+                .collect::<Vec<_>>();
+
+            log::info!(
+                "[pollux.cargo] nothing to resolve for {} (not following {:?})",
+                cargo_package,
+                skipped
+            );
+
+            let resolved = ResolvedDependencies {
+                packages: vec![],
+                unverifiable: vec![],
+                graph: DependencyGraph::new(vec![cargo_package.clone()], HashMap::new()),
+            };
+
+            return Ok(resolved);
+        }
+
+        // Extracted sources live within our own cache, hence generating a lockfile there is fine
         let download_path = self.crate_downloader.download_extract(cargo_package).await?;
-        let local_resolver = LocalProjectDependenciesResolver::new(download_path);
-        local_resolver.resolve().await
+        self.resolve_project(download_path).await
+    }
+
+    pub async fn resolve_for_lockfile(&self, lockfile: &Lockfile) -> anyhow::Result<ResolvedDependencies> {
+        Ok(resolve_locked_packages(lockfile))
+    }
+
+    async fn resolve_project(&self, project_root: Utf8PathBuf) -> anyhow::Result<ResolvedDependencies> {
+        if !project_root.join("Cargo.lock").exists() {
+            generate_lockfile(&project_root)?;
+        }
+
+        match &self.metadata_filters {
+            Some(filters) => {
+                let metadata_resolver = CargoMetadataDependenciesResolver::new(project_root, filters.clone());
+                metadata_resolver.resolve().await
+            },
+            None => {
+                let local_resolver = LocalProjectDependenciesResolver::new(project_root);
+                local_resolver.resolve().await
+            },
+        }
+    }
+
+    fn copy_into_scratch_dir(&self, project_root: &Utf8PathBuf) -> anyhow::Result<Utf8PathBuf> {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos();
+        let scratch_root = self
+            .cache_manager
+            .scratch_dir()
+            .join(format!("{}-{}", std::process::id(), nanos));
+
+        log::info!(
+            "[pollux.cargo] copying {} into scratch dir {:?}",
+            project_root,
+            scratch_root
+        );
+        copy_project_tree(project_root.as_std_path(), &scratch_root)
+            .with_context(|| format!("pollux.cargo : cannot copy {} into scratch dir", project_root))?;
+
+        let scratch_root = Utf8PathBuf::try_from(scratch_root)?;
+        Ok(scratch_root)
     }
 }
 
@@ -38,77 +179,241 @@ impl LocalProjectDependenciesResolver {
         Self { project_root }
     }
 
-    async fn resolve(&self) -> anyhow::Result<Vec<CargoPackage>> {
-        let lockfile_path = self.locate_or_generate()?;
-        let lockfile = Lockfile::load(lockfile_path)?;
-        let crates = lockfile
-            .packages
-            .into_iter()
-            .filter(|pkg| {
-                if let Some(source) = &pkg.source {
-                    source.is_default_registry()
-                } else {
-                    false
-                }
-            })
-            .map(|pkg| CargoPackage::new(pkg.name.to_string(), pkg.version.to_string()))
-            .collect::<Vec<_>>();
-
-        Ok(crates)
-    }
-
-    fn locate_or_generate(&self) -> anyhow::Result<Utf8PathBuf> {
-        if !self.project_root.join("Cargo.lock").exists() {
-            match self.generate_lockfile() {
-                Ok(_) => {
-                    if !self.project_root.join("Cargo.lock").exists() {
-                        bail!("cargo command succeed but lockfile was not generated")
-                    }
-                    log::info!("[pollux.cargo] generated missing lockfile with success")
-                },
-                Err(e) => {
-                    log::error!("[pollux.cargo] cannot generate lockfile : {}", e);
-                    bail!(e)
-                },
-            }
-        }
+    async fn resolve(&self) -> anyhow::Result<ResolvedDependencies> {
+        let lockfile = Lockfile::load(self.project_root.join("Cargo.lock"))?;
+        Ok(resolve_locked_packages(&lockfile))
+    }
+}
 
-        Ok(self.project_root.join("Cargo.lock"))
+fn locate_workspace_root(project_path: &Utf8PathBuf) -> anyhow::Result<Utf8PathBuf> {
+    let output = Command::new("cargo")
+        .current_dir(project_path)
+        .args(["locate-project", "--workspace", "--message-format", "plain"])
+        .output()
+        .context("pollux.cargo : cannot run `cargo locate-project`")?;
+
+    if !output.status.success() {
+        bail!("pollux.cargo : cannot locate workspace root for {}", project_path);
     }
 
-    fn generate_lockfile(&self) -> anyhow::Result<()> {
-        log::info!("[pollux.cargo] project root : {:?}", self.project_root);
-        let cargo_update = Command::new("cargo")
-            .current_dir(&self.project_root)
-            .arg("update")
-            .arg("--workspace")
-            .status();
+    let workspace_manifest = Utf8PathBuf::from(String::from_utf8(output.stdout)?.trim());
+
+    let workspace_root = workspace_manifest.parent().with_context(|| {
+        format!(
+            "pollux.cargo : invalid workspace manifest path ({})",
+            workspace_manifest
+        )
+    })?;
 
-        match cargo_update {
-            Ok(status) => {
-                if !status.success() {
-                    log::error!("[pollux.cargo] cargo update failed");
-                    bail!("error when running `cargo update --workspace`")
-                }
+    log::info!("[pollux.cargo] located workspace root at {}", workspace_root);
+    Ok(workspace_root.to_path_buf())
+}
+
+// Build outputs and VCS data are irrelevant for dependency resolution and may be huge
+fn copy_project_tree(workspace_root: &Path, destination: &Path) -> anyhow::Result<()> {
+    let build_dirs = build_output_dirs(workspace_root, env::var_os("CARGO_TARGET_DIR"));
+    copy_tree(workspace_root, destination, &build_dirs, &mut HashSet::new())
+}
+
+// Nested directories named target may well hold sources, hence only actual build dirs are skipped.
+// Like cargo does, a relative CARGO_TARGET_DIR is resolved against the current directory
+fn build_output_dirs(workspace_root: &Path, cargo_target_dir: Option<OsString>) -> HashSet<PathBuf> {
+    let custom_target_dir = cargo_target_dir.and_then(|target_dir| absolute(target_dir).ok());
+
+    [Some(workspace_root.join("target")), custom_target_dir]
+        .into_iter()
+        .flatten()
+        .filter_map(|build_dir| fs::canonicalize(build_dir).ok())
+        .collect()
+}
+
+// Symlinks are followed, since projects may link shared sources or manifests,
+// while already copied directories are skipped so symlink cycles end up copied once
+fn copy_tree(
+    source: &Path,
+    destination: &Path,
+    build_dirs: &HashSet<PathBuf>,
+    copied_dirs: &mut HashSet<PathBuf>,
+) -> anyhow::Result<()> {
+    let canonical_source = fs::canonicalize(source)?;
+
+    if build_dirs.contains(&canonical_source) || !copied_dirs.insert(canonical_source) {
+        return Ok(());
+    }
+
+    fs::create_dir_all(destination)?;
+
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let target = destination.join(entry.file_name());
+
+        let metadata = match fs::metadata(entry.path()) {
+            Ok(metadata) => metadata,
+            Err(_) if entry.file_type()?.is_symlink() => {
+                log::warn!("[pollux.cargo] skipping dangling symlink {:?}", entry.path());
+                continue;
             },
-            Err(e) => {
-                log::error!("[pollux.cargo] cargo update failed: {}", e);
+            Err(incoming) => return Err(incoming.into()),
+        };
+
+        if metadata.is_dir() {
+            if entry.file_name() != ".git" {
+                copy_tree(&entry.path(), &target, build_dirs, copied_dirs)?;
+            }
+        } else if metadata.is_file() {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_lockfile(project_root: &Utf8PathBuf) -> anyhow::Result<()> {
+    log::info!("[pollux.cargo] generating lockfile at : {:?}", project_root);
+    let cargo_update = Command::new("cargo")
+        .current_dir(project_root)
+        .arg("update")
+        .arg("--workspace")
+        .status();
+
+    match cargo_update {
+        Ok(status) => {
+            if !status.success() {
+                log::error!("[pollux.cargo] cargo update failed");
                 bail!("error when running `cargo update --workspace`")
-            },
+            }
+        },
+        Err(e) => {
+            log::error!("[pollux.cargo] cargo update failed: {}", e);
+            bail!("error when running `cargo update --workspace`")
+        },
+    }
+
+    if !project_root.join("Cargo.lock").exists() {
+        bail!("cargo command succeed but lockfile was not generated")
+    }
+
+    log::info!("[pollux.cargo] generated missing lockfile with success");
+    Ok(())
+}
+
+fn resolve_locked_packages(lockfile: &Lockfile) -> ResolvedDependencies {
+    let locked_packages = lockfile
+        .packages
+        .iter()
+        .map(|pkg| (package_key(&pkg.name, &pkg.version), pkg))
+        .collect::<HashMap<_, _>>();
+
+    let dependencies = locked_packages
+        .values()
+        .map(|pkg| {
+            let dependencies = pkg
+                .dependencies
+                .iter()
+                .filter_map(|dependency| locked_packages.get(&package_key(&dependency.name, &dependency.version)))
+                .map(|locked| convert_locked_package(locked))
+                .collect::<Vec<_>>();
+            (convert_locked_package(pkg), dependencies)
+        })
+        .collect::<HashMap<_, _>>();
+
+    // Packages without a source are local ones (workspace members or path dependencies)
+    let local_packages = lockfile
+        .packages
+        .iter()
+        .filter(|pkg| pkg.source.is_none())
+        .map(convert_locked_package)
+        .collect::<Vec<_>>();
+
+    let crates = lockfile
+        .packages
+        .iter()
+        .filter(|pkg| {
+            if let Some(source) = &pkg.source {
+                source.is_default_registry()
+            } else {
+                false
+            }
+        })
+        .map(convert_locked_package)
+        .collect::<Vec<_>>();
+
+    // Sourceless packages can't be told apart from workspace members with a lockfile only,
+    // so only git and alternative registries are reported here
+    let unverifiable = lockfile
+        .packages
+        .iter()
+        .filter_map(|pkg| {
+            let source = pkg.source.as_ref().filter(|source| !source.is_default_registry())?;
+            let unverifiable_package = UnverifiablePackage {
+                cargo_package: convert_locked_package(pkg),
+                source: package_source(source),
+            };
+            Some(unverifiable_package)
+        })
+        .collect::<Vec<_>>();
+
+    ResolvedDependencies {
+        packages: crates,
+        unverifiable,
+        graph: DependencyGraph::new(local_packages, dependencies),
+    }
+}
+
+fn package_key(name: &Name, version: &Version) -> (String, String) {
+    (name.to_string(), version.to_string())
+}
+
+fn package_source(source_id: &SourceId) -> PackageSource {
+    let url = source_id.url();
+
+    if source_id.is_git() {
+        PackageSource::Git {
+            url: url.to_string(),
+            revision: source_id.precise().map(|revision| revision.to_string()),
         }
-        Ok(())
+    } else if source_id.is_path() {
+        PackageSource::Path {
+            path: url.path().to_string(),
+        }
+    } else {
+        PackageSource::Registry { url: url.to_string() }
     }
 }
 
+fn convert_locked_package(pkg: &Package) -> CargoPackage {
+    CargoPackage::with_checksum(
+        pkg.name.to_string(),
+        pkg.version.to_string(),
+        pkg.checksum.as_ref().map(|checksum| checksum.to_string()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::core::models::CargoPackage;
-    use crate::infra::networking::crates::resolvers::LocalProjectDependenciesResolver;
-    use assertor::EqualityAssertion;
+    use crate::core::models::{CargoPackage, DependencyKind, MetadataFilters, PackageSource, UnverifiablePackage};
+    use crate::infra::caching::CacheManager;
+    use crate::infra::networking::crates::resolvers::{
+        DependenciesResolver, LocalProjectDependenciesResolver, build_output_dirs, copy_project_tree,
+        locate_workspace_root,
+    };
+    use crate::infra::networking::crates::sparse::SparseIndexClient;
+    use crate::infra::networking::crates::tarballs::CrateArchiveDownloader;
+    use crate::infra::networking::http::HTTP_CLIENT;
+    use assertor::{BooleanAssertion, EqualityAssertion};
     use camino::Utf8PathBuf;
+    use httpmock::MockServer;
     use std::fs;
     use temp_dir::TempDir;
 
+    fn locked_package(name: &str, version: &str, checksum: &str) -> CargoPackage {
+        CargoPackage::with_checksum(name.to_string(), version.to_string(), Some(checksum.to_string()))
+    }
+
+    fn checksums(packages: &[CargoPackage]) -> Vec<Option<String>> {
+        packages.iter().map(|package| package.checksum.clone()).collect()
+    }
+
     #[tokio::test]
     async fn should_extract_packages_from_lockfile() {
         let lockfile_contents = r#"
@@ -133,6 +438,9 @@ mod tests {
             version = "2.8.0"
             source = "registry+https://github.com/rust-lang/crates.io-index"
             checksum = "8f68f53c83ab957f72c32642f3868eec03eb974d1fb82e453128456482613d36"
+            dependencies = [
+                "cfg-if"
+            ]
 
             [[package]]
             name = "cfg-if"
@@ -146,9 +454,74 @@ mod tests {
             dependencies = [
                 "arbitrary",
                 "autocfg",
+                "bitflags"
+            ]
+        "#;
+
+        let cargo_project = TempDir::new().expect("Cant create temp dir");
+
+        let lockfile_path = cargo_project.path().join("Cargo.lock");
+        fs::write(&lockfile_path, lockfile_contents).expect("failed to cargo manifest file");
+
+        let resolver = LocalProjectDependenciesResolver {
+            project_root: Utf8PathBuf::try_from(cargo_project.path().to_path_buf()).unwrap(),
+        };
+
+        let dependencies = resolver.resolve().await.expect("resolve_dependencies failed");
+
+        let expected_packages = vec![
+            locked_package(
+                "arbitrary",
+                "1.4.1",
+                "dde20b3d026af13f561bdd0f15edf01fc734f0dafcedbaf42bba506a9517f223",
+            ),
+            locked_package(
+                "autocfg",
+                "1.4.0",
+                "ace50bade8e6234aa140d9a2f552bbee1db4d353f69b8217bc503490fc1a9f26",
+            ),
+            locked_package(
                 "bitflags",
+                "2.8.0",
+                "8f68f53c83ab957f72c32642f3868eec03eb974d1fb82e453128456482613d36",
+            ),
+            locked_package(
+                "cfg-if",
+                "1.0.0",
+                "baf1de4339761588bc0619e3cbc0120ee582ebb74b53b4efbf79117bd2da40fd",
+            ),
+        ];
+
+        assertor::assert_that!(checksums(&dependencies.packages)).is_equal_to(checksums(&expected_packages));
+        assertor::assert_that!(dependencies.packages).is_equal_to(expected_packages);
+    }
+
+    #[tokio::test]
+    async fn should_compute_dependency_depths_from_lockfile() {
+        let lockfile_contents = r#"
+            version = 3
+
+            [[package]]
+            name = "bitflags"
+            version = "2.8.0"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+            checksum = "8f68f53c83ab957f72c32642f3868eec03eb974d1fb82e453128456482613d36"
+            dependencies = [
                 "cfg-if"
             ]
+
+            [[package]]
+            name = "cfg-if"
+            version = "1.0.0"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+            checksum = "baf1de4339761588bc0619e3cbc0120ee582ebb74b53b4efbf79117bd2da40fd"
+
+            [[package]]
+            name = "my-project"
+            version = "1.0.0"
+            dependencies = [
+                "bitflags"
+            ]
         "#;
 
         let cargo_project = TempDir::new().expect("Cant create temp dir");
@@ -162,13 +535,266 @@ mod tests {
 
         let dependencies = resolver.resolve().await.expect("resolve_dependencies failed");
 
-        let expected_packages = vec![
-            CargoPackage::with("arbitrary", "1.4.1"),
-            CargoPackage::with("autocfg", "1.4.0"),
-            CargoPackage::with("bitflags", "2.8.0"),
-            CargoPackage::with("cfg-if", "1.0.0"),
+        let bitflags = locked_package(
+            "bitflags",
+            "2.8.0",
+            "8f68f53c83ab957f72c32642f3868eec03eb974d1fb82e453128456482613d36",
+        );
+
+        let cfg_if = locked_package(
+            "cfg-if",
+            "1.0.0",
+            "baf1de4339761588bc0619e3cbc0120ee582ebb74b53b4efbf79117bd2da40fd",
+        );
+
+        let depths = dependencies.graph.depths();
+
+        assertor::assert_that!(depths[&bitflags]).is_equal_to(1);
+        assertor::assert_that!(depths[&cfg_if]).is_equal_to(2);
+    }
+
+    #[tokio::test]
+    async fn should_report_packages_from_unverifiable_sources() {
+        let lockfile_contents = r#"
+            version = 3
+
+            [[package]]
+            name = "cfg-if"
+            version = "1.0.0"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+            checksum = "baf1de4339761588bc0619e3cbc0120ee582ebb74b53b4efbf79117bd2da40fd"
+
+            [[package]]
+            name = "internal-utils"
+            version = "0.3.0"
+            source = "registry+https://registry.example.com/index"
+            checksum = "8f68f53c83ab957f72c32642f3868eec03eb974d1fb82e453128456482613d36"
+
+            [[package]]
+            name = "my-project"
+            version = "1.0.0"
+            dependencies = [
+                "cfg-if",
+                "internal-utils",
+                "patched-tls",
+            ]
+
+            [[package]]
+            name = "patched-tls"
+            version = "0.1.0"
+            source = "git+https://github.com/example/patched-tls?branch=main#3c1a5e7f9b2d4c6e8a0f1b3d5c7e9a1b3d5f7a9c"
+        "#;
+
+        let cargo_project = TempDir::new().expect("Cant create temp dir");
+
+        let lockfile_path = cargo_project.path().join("Cargo.lock");
+        fs::write(&lockfile_path, lockfile_contents).expect("failed to cargo manifest file");
+
+        let resolver = LocalProjectDependenciesResolver {
+            project_root: Utf8PathBuf::try_from(cargo_project.path().to_path_buf()).unwrap(),
+        };
+
+        let dependencies = resolver.resolve().await.expect("resolve_dependencies failed");
+
+        let expected = vec![
+            UnverifiablePackage {
+                cargo_package: locked_package(
+                    "internal-utils",
+                    "0.3.0",
+                    "8f68f53c83ab957f72c32642f3868eec03eb974d1fb82e453128456482613d36",
+                ),
+                source: PackageSource::Registry {
+                    url: "https://registry.example.com/index".to_string(),
+                },
+            },
+            UnverifiablePackage {
+                cargo_package: CargoPackage::with("patched-tls", "0.1.0"),
+                source: PackageSource::Git {
+                    url: "https://github.com/example/patched-tls".to_string(),
+                    revision: Some("3c1a5e7f9b2d4c6e8a0f1b3d5c7e9a1b3d5f7a9c".to_string()),
+                },
+            },
         ];
 
-        assertor::assert_that!(dependencies).is_equal_to(expected_packages)
+        assertor::assert_that!(dependencies.packages.len()).is_equal_to(1);
+        assertor::assert_that!(dependencies.unverifiable).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_copy_project_tree_without_build_outputs() {
+        let cargo_project = TempDir::new().expect("Cant create temp dir");
+        let project_root = cargo_project.path();
+
+        fs::create_dir_all(project_root.join("src")).expect("failed to create sources dir");
+        fs::create_dir_all(project_root.join("src/target")).expect("failed to create nested sources dir");
+        fs::create_dir_all(project_root.join("target/debug")).expect("failed to create target dir");
+        fs::write(project_root.join("Cargo.toml"), "[package]").expect("failed to write manifest");
+        fs::write(project_root.join("src/main.rs"), "fn main() {}").expect("failed to write sources");
+        fs::write(project_root.join("src/target/mod.rs"), "pub fn target() {}").expect("failed to write sources");
+        fs::write(project_root.join("target/debug/app"), "binary").expect("failed to write build output");
+
+        let scratch_dir = TempDir::new().expect("Cant create temp dir");
+        let scratch_root = scratch_dir.path().join("copy");
+
+        copy_project_tree(project_root, &scratch_root).unwrap();
+
+        assertor::assert_that!(scratch_root.join("Cargo.toml").exists()).is_true();
+        assertor::assert_that!(scratch_root.join("src/main.rs").exists()).is_true();
+        assertor::assert_that!(scratch_root.join("src/target/mod.rs").exists()).is_true();
+        assertor::assert_that!(scratch_root.join("target").exists()).is_false();
+    }
+
+    #[test]
+    fn should_include_custom_target_dir_within_build_output_dirs() {
+        let cargo_project = TempDir::new().expect("Cant create temp dir");
+        let project_root = cargo_project.path();
+
+        fs::create_dir_all(project_root.join("build/debug")).expect("failed to create target dir");
+        fs::write(project_root.join("build/debug/app"), "binary").expect("failed to write build output");
+
+        let custom_target_dir = project_root.join("build").into_os_string();
+        let build_dirs = build_output_dirs(project_root, Some(custom_target_dir));
+
+        let expected = fs::canonicalize(project_root.join("build")).unwrap();
+        assertor::assert_that!(build_dirs.contains(&expected)).is_true();
+        assertor::assert_that!(build_dirs.len()).is_equal_to(1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_copy_project_tree_following_symlinks() {
+        let cargo_project = TempDir::new().expect("Cant create temp dir");
+        let shared_sources = TempDir::new().expect("Cant create temp dir");
+        let project_root = cargo_project.path();
+
+        fs::write(project_root.join("Cargo.toml"), "[package]").expect("failed to write manifest");
+        fs::write(shared_sources.path().join("lib.rs"), "pub fn shared() {}").expect("failed to write sources");
+        std::os::unix::fs::symlink(shared_sources.path(), project_root.join("src")).expect("failed to link sources");
+        std::os::unix::fs::symlink(project_root, project_root.join("itself")).expect("failed to link project");
+
+        let scratch_dir = TempDir::new().expect("Cant create temp dir");
+        let scratch_root = scratch_dir.path().join("copy");
+
+        copy_project_tree(project_root, &scratch_root).unwrap();
+
+        assertor::assert_that!(scratch_root.join("src/lib.rs").is_file()).is_true();
+        assertor::assert_that!(scratch_root.join("src").is_symlink()).is_false();
+        assertor::assert_that!(scratch_root.join("itself").exists()).is_false();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_copy_project_tree_skipping_dangling_symlinks() {
+        let cargo_project = TempDir::new().expect("Cant create temp dir");
+        let project_root = cargo_project.path();
+
+        fs::write(project_root.join("Cargo.toml"), "[package]").expect("failed to write manifest");
+        std::os::unix::fs::symlink(project_root.join("missing"), project_root.join("dangling"))
+            .expect("failed to link missing file");
+
+        let scratch_dir = TempDir::new().expect("Cant create temp dir");
+        let scratch_root = scratch_dir.path().join("copy");
+
+        copy_project_tree(project_root, &scratch_root).unwrap();
+
+        assertor::assert_that!(scratch_root.join("Cargo.toml").is_file()).is_true();
+        assertor::assert_that!(scratch_root.join("dangling").exists()).is_false();
+    }
+
+    #[test]
+    fn should_locate_workspace_root_from_member() {
+        let cargo_workspace = TempDir::new().expect("Cant create temp dir");
+        let workspace_root = cargo_workspace.path();
+        let member_root = workspace_root.join("member");
+
+        fs::create_dir_all(member_root.join("src")).expect("failed to create sources dir");
+        fs::write(
+            workspace_root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"member\"]\n",
+        )
+        .expect("failed to write workspace manifest");
+        fs::write(
+            member_root.join("Cargo.toml"),
+            "[package]\nname = \"member\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+        )
+        .expect("failed to write member manifest");
+        fs::write(member_root.join("src/lib.rs"), "").expect("failed to write sources");
+
+        let member_path = Utf8PathBuf::try_from(member_root).unwrap();
+        let located = locate_workspace_root(&member_path).unwrap();
+
+        let expected = Utf8PathBuf::try_from(fs::canonicalize(workspace_root).unwrap()).unwrap();
+        let located = Utf8PathBuf::try_from(fs::canonicalize(located).unwrap()).unwrap();
+        assertor::assert_that!(located).is_equal_to(expected);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_missing_lockfile_in_locked_mode() {
+        let cargo_project = TempDir::new().expect("Cant create temp dir");
+        fs::write(cargo_project.path().join("Cargo.toml"), "[package]").expect("failed to write manifest");
+
+        let sparse_index = SparseIndexClient::new(
+            "http://localhost".to_string(),
+            HTTP_CLIENT.clone(),
+            CacheManager::get().sparse_index_cache_dir(),
+        );
+        let downloader = CrateArchiveDownloader::new(
+            "http://localhost".to_string(),
+            HTTP_CLIENT.clone(),
+            sparse_index,
+            CacheManager::get(),
+        );
+        let resolver = DependenciesResolver::new(downloader, CacheManager::get(), None, true);
+
+        let project_path = Utf8PathBuf::try_from(cargo_project.path().to_path_buf()).unwrap();
+        let resolved = resolver.resolve_for_local_project(project_path).await;
+
+        assertor::assert_that!(resolved.is_err()).is_true();
+        assertor::assert_that!(cargo_project.path().join("Cargo.lock").exists()).is_false();
+    }
+
+    #[tokio::test]
+    async fn should_skip_downloads_for_crates_without_followed_dependencies() {
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+
+        let sparse_index = SparseIndexClient::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            cache_dir.path().to_path_buf(),
+        );
+        let downloader = CrateArchiveDownloader::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            sparse_index,
+            CacheManager::get(),
+        );
+
+        let filters = MetadataFilters {
+            kinds: vec![DependencyKind::Normal, DependencyKind::Build],
+            ..MetadataFilters::default()
+        };
+
+        let resolver = DependenciesResolver::new(downloader, CacheManager::get(), Some(filters), false);
+
+        let index_file = mock_server.mock(|when, then| {
+            when.method("GET").path("/ca/st/castaway");
+            then.status(200).body(
+                r#"{"name":"castaway","vers":"0.2.4","deps":[{"name":"paste","req":"^1","kind":"dev"}],"cksum":"dec551ab6e7578819132c713a93c022a05d60159dc86e7a7050223577484c55a","features":{},"yanked":false}"#,
+            );
+        });
+
+        let tarball = mock_server.mock(|when, then| {
+            when.method("GET").path("/castaway/castaway-0.2.4.crate");
+            then.status(500);
+        });
+
+        let cargo_package = CargoPackage::with("castaway", "0.2.4");
+        let resolved = resolver.resolve_for_crate_package(&cargo_package).await.unwrap();
+
+        index_file.assert();
+        tarball.assert_calls(0);
+        assertor::assert_that!(resolved.packages.is_empty()).is_true();
+        assertor::assert_that!(resolved.graph.is_local(&cargo_package)).is_true();
     }
 }
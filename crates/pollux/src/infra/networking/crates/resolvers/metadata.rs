@@ -0,0 +1,430 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::graphs::DependencyGraph;
+use crate::core::models::{CargoPackage, DependencyKind, MetadataFilters, ResolvedDependencies, UnverifiablePackage};
+use crate::infra::networking::crates::resolvers::package_source;
+use anyhow::{Context, bail};
+use camino::Utf8PathBuf;
+use cargo_lock::{Lockfile, SourceId};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::process::Command;
+
+// See https://doc.rust-lang.org/cargo/commands/cargo-metadata.html#json-format
+#[derive(Debug, Deserialize)]
+struct CargoMetadata {
+    packages: Vec<MetadataPackage>,
+    workspace_members: Vec<String>,
+    resolve: Option<MetadataResolve>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataPackage {
+    id: String,
+    name: String,
+    version: String,
+    source: Option<String>,
+    manifest_path: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataResolve {
+    nodes: Vec<MetadataNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataNode {
+    id: String,
+    #[serde(default)]
+    deps: Vec<MetadataNodeDependency>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataNodeDependency {
+    pkg: String,
+    #[serde(default)]
+    dep_kinds: Vec<MetadataDependencyKind>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataDependencyKind {
+    kind: Option<String>,
+}
+
+impl MetadataDependencyKind {
+    fn dependency_kind(&self) -> DependencyKind {
+        match self.kind.as_deref() {
+            Some("build") => DependencyKind::Build,
+            Some("dev") => DependencyKind::Dev,
+            _ => DependencyKind::Normal,
+        }
+    }
+}
+
+pub struct CargoMetadataDependenciesResolver {
+    project_root: Utf8PathBuf,
+    filters: MetadataFilters,
+}
+
+impl CargoMetadataDependenciesResolver {
+    pub fn new(project_root: Utf8PathBuf, filters: MetadataFilters) -> Self {
+        Self { project_root, filters }
+    }
+
+    pub async fn resolve(&self) -> anyhow::Result<ResolvedDependencies> {
+        let metadata = self.run_cargo_metadata()?;
+
+        // cargo metadata does not report checksums, which are tracked by the lockfile
+        let lockfile = Lockfile::load(self.project_root.join("Cargo.lock"))?;
+        let checksums = lockfile
+            .packages
+            .into_iter()
+            .filter_map(|pkg| {
+                let checksum = pkg.checksum?.to_string();
+                Some(((pkg.name.to_string(), pkg.version.to_string()), checksum))
+            })
+            .collect::<HashMap<_, _>>();
+
+        build_resolved_dependencies(metadata, &self.filters.kinds, &checksums)
+    }
+
+    fn run_cargo_metadata(&self) -> anyhow::Result<CargoMetadata> {
+        log::info!("[pollux.cargo] running cargo metadata at : {:?}", self.project_root);
+
+        let output = Command::new("cargo")
+            .current_dir(&self.project_root)
+            .args(metadata_arguments(&self.filters))
+            .output()
+            .context("pollux.cargo : cannot run `cargo metadata`")?;
+
+        if !output.status.success() {
+            log::error!(
+                "[pollux.cargo] cargo metadata failed : {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            bail!("error when running `cargo metadata`")
+        }
+
+        let metadata = serde_json::from_slice(&output.stdout).context("pollux.cargo : invalid cargo metadata")?;
+        Ok(metadata)
+    }
+}
+
+fn metadata_arguments(filters: &MetadataFilters) -> Vec<String> {
+    // Lockfiles are never updated in place, so they must be up-to-date with manifests
+    let mut arguments = vec![
+        "metadata".to_string(),
+        "--format-version".to_string(),
+        "1".to_string(),
+        "--locked".to_string(),
+    ];
+
+    if let Some(target) = &filters.target {
+        arguments.push("--filter-platform".to_string());
+        arguments.push(target.clone());
+    }
+
+    if !filters.features.is_empty() {
+        arguments.push("--features".to_string());
+        arguments.push(filters.features.join(","));
+    }
+
+    if filters.all_features {
+        arguments.push("--all-features".to_string());
+    }
+
+    if filters.no_default_features {
+        arguments.push("--no-default-features".to_string());
+    }
+
+    arguments
+}
+
+fn build_resolved_dependencies(
+    metadata: CargoMetadata,
+    kinds: &[DependencyKind],
+    checksums: &HashMap<(String, String), String>,
+) -> anyhow::Result<ResolvedDependencies> {
+    let Some(resolve) = metadata.resolve else {
+        bail!("pollux.cargo : cargo metadata did not resolve dependencies");
+    };
+
+    // Only edges of the requested kinds are followed
+    let edges = resolve
+        .nodes
+        .into_iter()
+        .map(|node| {
+            let dependencies = node
+                .deps
+                .into_iter()
+                .filter(|dependency| {
+                    dependency
+                        .dep_kinds
+                        .iter()
+                        .any(|dep_kind| kinds.contains(&dep_kind.dependency_kind()))
+                })
+                .map(|dependency| dependency.pkg)
+                .collect::<Vec<_>>();
+            (node.id, dependencies)
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut reachable = metadata.workspace_members.iter().collect::<HashSet<_>>();
+    let mut to_visit = metadata.workspace_members.iter().collect::<VecDeque<_>>();
+
+    while let Some(current) = to_visit.pop_front() {
+        for dependency in edges.get(current).into_iter().flatten() {
+            if reachable.insert(dependency) {
+                to_visit.push_back(dependency);
+            }
+        }
+    }
+
+    let packages = metadata
+        .packages
+        .iter()
+        .filter(|pkg| reachable.contains(&pkg.id))
+        .map(|pkg| {
+            let key = (pkg.name.clone(), pkg.version.clone());
+            let cargo_package =
+                CargoPackage::with_checksum(pkg.name.clone(), pkg.version.clone(), checksums.get(&key).cloned());
+            (pkg.id.as_str(), cargo_package)
+        })
+        .collect::<HashMap<_, _>>();
+
+    let dependencies = packages
+        .iter()
+        .map(|(id, cargo_package)| {
+            let dependencies = edges
+                .get(*id)
+                .into_iter()
+                .flatten()
+                .filter_map(|dependency| packages.get(dependency.as_str()).cloned())
+                .collect::<Vec<_>>();
+            (cargo_package.clone(), dependencies)
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut local_packages = vec![];
+    let mut crates = vec![];
+    let mut unverifiable = vec![];
+
+    for pkg in metadata.packages.iter().filter(|pkg| reachable.contains(&pkg.id)) {
+        let cargo_package = packages[pkg.id.as_str()].clone();
+
+        let source_id = match &pkg.source {
+            Some(source) => SourceId::from_url(source)?,
+            None if metadata.workspace_members.contains(&pkg.id) => {
+                local_packages.push(cargo_package);
+                continue;
+            },
+            // Unlike Cargo.lock, cargo metadata tells path dependencies apart from workspace members
+            None => {
+                let package_root = pkg.manifest_path.parent().unwrap_or(&pkg.manifest_path);
+                SourceId::for_path(package_root)?
+            },
+        };
+
+        match source_id.is_default_registry() {
+            true => crates.push(cargo_package),
+            false => unverifiable.push(UnverifiablePackage {
+                cargo_package,
+                source: package_source(&source_id),
+            }),
+        }
+    }
+
+    let resolved = ResolvedDependencies {
+        packages: crates,
+        unverifiable,
+        graph: DependencyGraph::new(local_packages, dependencies),
+    };
+
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{CargoPackage, DependencyKind, MetadataFilters, PackageSource, UnverifiablePackage};
+    use crate::infra::networking::crates::resolvers::metadata::{
+        CargoMetadata, build_resolved_dependencies, metadata_arguments,
+    };
+    use assertor::EqualityAssertion;
+    use std::collections::HashMap;
+
+    static FAKE_METADATA: &str = r#"
+        {
+          "packages": [
+            {
+              "id": "path+file:///fake/my-project#0.1.0",
+              "name": "my-project",
+              "version": "0.1.0",
+              "source": null,
+              "manifest_path": "/fake/my-project/Cargo.toml"
+            },
+            {
+              "id": "path+file:///fake/vendor/zlib#1.3.1",
+              "name": "zlib",
+              "version": "1.3.1",
+              "source": null,
+              "manifest_path": "/fake/vendor/zlib/Cargo.toml"
+            },
+            {
+              "id": "git+https://github.com/example/patched-tls#0.1.0",
+              "name": "patched-tls",
+              "version": "0.1.0",
+              "source": "git+https://github.com/example/patched-tls#3c1a5e7f9b2d4c6e8a0f1b3d5c7e9a1b3d5f7a9c",
+              "manifest_path": "/fake/.cargo/git/checkouts/patched-tls/Cargo.toml"
+            },
+            {
+              "id": "registry+https://github.com/rust-lang/crates.io-index#libc@0.2.175",
+              "name": "libc",
+              "version": "0.2.175",
+              "source": "registry+https://github.com/rust-lang/crates.io-index",
+              "manifest_path": "/fake/.cargo/registry/libc-0.2.175/Cargo.toml"
+            },
+            {
+              "id": "registry+https://github.com/rust-lang/crates.io-index#cc@1.2.36",
+              "name": "cc",
+              "version": "1.2.36",
+              "source": "registry+https://github.com/rust-lang/crates.io-index",
+              "manifest_path": "/fake/.cargo/registry/cc-1.2.36/Cargo.toml"
+            },
+            {
+              "id": "registry+https://github.com/rust-lang/crates.io-index#assertor@0.0.4",
+              "name": "assertor",
+              "version": "0.0.4",
+              "source": "registry+https://github.com/rust-lang/crates.io-index",
+              "manifest_path": "/fake/.cargo/registry/assertor-0.0.4/Cargo.toml"
+            }
+          ],
+          "workspace_members": ["path+file:///fake/my-project#0.1.0"],
+          "resolve": {
+            "nodes": [
+              {
+                "id": "path+file:///fake/my-project#0.1.0",
+                "deps": [
+                  {
+                    "pkg": "registry+https://github.com/rust-lang/crates.io-index#libc@0.2.175",
+                    "dep_kinds": [{ "kind": null, "target": null }]
+                  },
+                  {
+                    "pkg": "registry+https://github.com/rust-lang/crates.io-index#cc@1.2.36",
+                    "dep_kinds": [{ "kind": "build", "target": null }]
+                  },
+                  {
+                    "pkg": "path+file:///fake/vendor/zlib#1.3.1",
+                    "dep_kinds": [{ "kind": null, "target": null }]
+                  },
+                  {
+                    "pkg": "git+https://github.com/example/patched-tls#0.1.0",
+                    "dep_kinds": [{ "kind": "dev", "target": null }]
+                  },
+                  {
+                    "pkg": "registry+https://github.com/rust-lang/crates.io-index#assertor@0.0.4",
+                    "dep_kinds": [{ "kind": "dev", "target": null }]
+                  }
+                ]
+              },
+              { "id": "path+file:///fake/vendor/zlib#1.3.1", "deps": [] },
+              { "id": "git+https://github.com/example/patched-tls#0.1.0", "deps": [] },
+              { "id": "registry+https://github.com/rust-lang/crates.io-index#libc@0.2.175", "deps": [] },
+              { "id": "registry+https://github.com/rust-lang/crates.io-index#cc@1.2.36", "deps": [] },
+              { "id": "registry+https://github.com/rust-lang/crates.io-index#assertor@0.0.4", "deps": [] }
+            ]
+          }
+        }
+    "#;
+
+    fn resolved_names(kinds: &[DependencyKind]) -> Vec<String> {
+        let metadata: CargoMetadata = serde_json::from_str(FAKE_METADATA).unwrap();
+        let checksums = HashMap::new();
+        let resolved = build_resolved_dependencies(metadata, kinds, &checksums).unwrap();
+        resolved.packages.into_iter().map(|pkg| pkg.name).collect()
+    }
+
+    #[test]
+    fn should_resolve_only_requested_dependency_kinds() {
+        let everything = resolved_names(&[DependencyKind::Normal, DependencyKind::Build, DependencyKind::Dev]);
+        let shipped = resolved_names(&[DependencyKind::Normal]);
+
+        assertor::assert_that!(everything).is_equal_to(vec![
+            "libc".to_string(),
+            "cc".to_string(),
+            "assertor".to_string(),
+        ]);
+        assertor::assert_that!(shipped).is_equal_to(vec!["libc".to_string()]);
+    }
+
+    #[test]
+    fn should_report_path_and_git_dependencies_as_unverifiable() {
+        let metadata: CargoMetadata = serde_json::from_str(FAKE_METADATA).unwrap();
+        let checksums = HashMap::new();
+        let kinds = [DependencyKind::Normal, DependencyKind::Dev];
+
+        let resolved = build_resolved_dependencies(metadata, &kinds, &checksums).unwrap();
+
+        let expected = vec![
+            UnverifiablePackage {
+                cargo_package: CargoPackage::with("zlib", "1.3.1"),
+                source: PackageSource::Path {
+                    path: "/fake/vendor/zlib".to_string(),
+                },
+            },
+            UnverifiablePackage {
+                cargo_package: CargoPackage::with("patched-tls", "0.1.0"),
+                source: PackageSource::Git {
+                    url: "https://github.com/example/patched-tls".to_string(),
+                    revision: Some("3c1a5e7f9b2d4c6e8a0f1b3d5c7e9a1b3d5f7a9c".to_string()),
+                },
+            },
+        ];
+
+        assertor::assert_that!(resolved.unverifiable).is_equal_to(expected);
+        assertor::assert_that!(resolved.graph.depths()[&CargoPackage::with("zlib", "1.3.1")]).is_equal_to(1);
+    }
+
+    #[test]
+    fn should_attach_checksums_from_lockfile() {
+        let metadata: CargoMetadata = serde_json::from_str(FAKE_METADATA).unwrap();
+        let checksums = HashMap::from([(("libc".to_string(), "0.2.175".to_string()), "6a2b6f4c1e3d".to_string())]);
+
+        let resolved = build_resolved_dependencies(metadata, &[DependencyKind::Normal], &checksums).unwrap();
+
+        let expected = CargoPackage::with_checksum(
+            "libc".to_string(),
+            "0.2.175".to_string(),
+            Some("6a2b6f4c1e3d".to_string()),
+        );
+        assertor::assert_that!(resolved.packages[0].checksum.clone()).is_equal_to(expected.checksum.clone());
+        assertor::assert_that!(resolved.packages).is_equal_to(vec![expected]);
+    }
+
+    #[test]
+    fn should_forward_filters_to_cargo_metadata() {
+        let filters = MetadataFilters {
+            target: Some("x86_64-unknown-linux-gnu".to_string()),
+            features: vec!["tls".to_string(), "http2".to_string()],
+            no_default_features: true,
+            ..Default::default()
+        };
+
+        let expected = [
+            "metadata",
+            "--format-version",
+            "1",
+            "--locked",
+            "--filter-platform",
+            "x86_64-unknown-linux-gnu",
+            "--features",
+            "tls,http2",
+            "--no-default-features",
+        ]
+        .map(|argument| argument.to_string())
+        .to_vec();
+
+        assertor::assert_that!(metadata_arguments(&filters)).is_equal_to(expected);
+    }
+}
@@ -0,0 +1,280 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::DependencyKind;
+use crate::infra::networking::http::HTTPClient;
+use anyhow::{Context, bail};
+use reqwest::StatusCode;
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// Served through a CDN, hence not subject to crates.io API rate limits
+pub static URL_CRATES_SPARSE_INDEX: &str = "https://index.crates.io";
+
+// See https://doc.rust-lang.org/cargo/reference/registry-index.html#json-schema
+#[derive(Debug, Deserialize)]
+pub struct IndexedCrateVersion {
+    pub vers: String,
+    pub cksum: String,
+    pub yanked: bool,
+    #[serde(default)]
+    pub deps: Vec<IndexedDependency>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IndexedDependency {
+    pub name: String,
+    pub kind: Option<String>,
+}
+
+impl IndexedDependency {
+    pub fn dependency_kind(&self) -> DependencyKind {
+        match self.kind.as_deref() {
+            Some("build") => DependencyKind::Build,
+            Some("dev") => DependencyKind::Dev,
+            _ => DependencyKind::Normal,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedIndexFile {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    contents: String,
+}
+
+pub struct SparseIndexClient {
+    base_url: String,
+    http_client: Arc<HTTPClient>,
+    cache_dir: PathBuf,
+}
+
+impl SparseIndexClient {
+    pub fn new(base_url: String, http_client: Arc<HTTPClient>, cache_dir: PathBuf) -> Self {
+        Self {
+            base_url,
+            http_client,
+            cache_dir,
+        }
+    }
+
+    pub async fn get_crate_versions(&self, crate_name: &str) -> anyhow::Result<Vec<IndexedCrateVersion>> {
+        let contents = self.fetch_index_file(crate_name).await?;
+
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<IndexedCrateVersion>(line)
+                    .with_context(|| format!("pollux.index : invalid index entry for {}", crate_name))
+            })
+            .collect()
+    }
+
+    pub async fn get_crate_version(
+        &self,
+        crate_name: &str,
+        crate_version: &str,
+    ) -> anyhow::Result<IndexedCrateVersion> {
+        let Some(indexed) = self
+            .get_crate_versions(crate_name)
+            .await?
+            .into_iter()
+            .find(|indexed| indexed.vers == crate_version)
+        else {
+            bail!(
+                "pollux.index : {}@{} has never been published",
+                crate_name,
+                crate_version
+            )
+        };
+
+        Ok(indexed)
+    }
+
+    async fn fetch_index_file(&self, crate_name: &str) -> anyhow::Result<String> {
+        let index_path = index_path(crate_name);
+        let cache_file = self.cache_dir.join(format!("{}.json", index_path.replace('/', "_")));
+
+        let cached = fs::read(&cache_file)
+            .ok()
+            .and_then(|serialized| serde_json::from_slice::<CachedIndexFile>(&serialized).ok());
+
+        let endpoint = format!("{}/{}", self.base_url, index_path);
+        let mut request = self.http_client.get(&endpoint);
+
+        // Conditional requests let the CDN answer with an empty 304 when nothing changed
+        if let Some(cached) = &cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = request.send().await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(cached) = cached
+        {
+            log::info!("[pollux.index] index entry for {} not modified", crate_name);
+            return Ok(cached.contents);
+        }
+
+        let response = response
+            .error_for_status()
+            .with_context(|| format!("pollux.index : cannot fetch index entry for {}", crate_name))?;
+
+        let header_value = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string())
+        };
+
+        let etag = header_value(ETAG);
+        let last_modified = header_value(LAST_MODIFIED);
+        let contents = response.text().await?;
+
+        let fresh = CachedIndexFile {
+            etag,
+            last_modified,
+            contents,
+        };
+
+        if fresh.etag.is_some() || fresh.last_modified.is_some() {
+            fs::create_dir_all(&self.cache_dir)?;
+            fs::write(&cache_file, serde_json::to_vec(&fresh)?)?;
+        }
+
+        Ok(fresh.contents)
+    }
+}
+
+// See https://doc.rust-lang.org/cargo/reference/registry-index.html#index-files
+fn index_path(crate_name: &str) -> String {
+    let name = crate_name.to_lowercase();
+
+    match name.len() {
+        1 => format!("1/{}", name),
+        2 => format!("2/{}", name),
+        3 => format!("3/{}/{}", &name[..1], name),
+        _ => format!("{}/{}/{}", &name[..2], &name[2..4], name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::DependencyKind;
+    use crate::infra::networking::crates::sparse::{SparseIndexClient, index_path};
+    use crate::infra::networking::http::HTTP_CLIENT;
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use httpmock::MockServer;
+    use temp_dir::TempDir;
+
+    static FAKE_INDEX_FILE: &str = r#"{"name":"serde","vers":"1.0.227","deps":[{"name":"serde_derive","req":"=1.0.227","features":[],"optional":true,"default_features":true,"target":null,"kind":"normal"},{"name":"serde_derive","req":"^1","features":[],"optional":false,"default_features":true,"target":null,"kind":"dev"}],"cksum":"80ece43fc6fbed4eb5392ab50c07334d3e577cbf40997ee896fe7af40bba4245","features":{},"yanked":true}
+{"name":"serde","vers":"1.0.228","deps":[],"cksum":"9a8e94ea7f378bd32cbbd37198a4a91436180c5bb472411e48b5ec2e2124ae9e","features":{},"yanked":false}
+"#;
+
+    #[test]
+    fn should_compute_index_paths() {
+        assertor::assert_that!(index_path("a")).is_equal_to("1/a".to_string());
+        assertor::assert_that!(index_path("cc")).is_equal_to("2/cc".to_string());
+        assertor::assert_that!(index_path("syn")).is_equal_to("3/s/syn".to_string());
+        assertor::assert_that!(index_path("Serde")).is_equal_to("se/rd/serde".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_parse_versions_from_index_file() {
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+        let client = SparseIndexClient::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            cache_dir.path().to_path_buf(),
+        );
+
+        let mocked = mock_server.mock(|when, then| {
+            when.method("GET").path("/se/rd/serde");
+            then.status(200).body(FAKE_INDEX_FILE);
+        });
+
+        let indexed = client.get_crate_version("serde", "1.0.227").await.unwrap();
+        let missing = client.get_crate_version("serde", "0.0.1").await;
+
+        mocked.assert_calls(2);
+        assertor::assert_that!(indexed.yanked).is_true();
+        assertor::assert_that!(missing.is_err()).is_true();
+    }
+
+    #[tokio::test]
+    async fn should_revalidate_cached_index_files_with_etag() {
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+        let client = SparseIndexClient::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            cache_dir.path().to_path_buf(),
+        );
+
+        let mut first_fetch = mock_server.mock(|when, then| {
+            when.method("GET").path("/se/rd/serde");
+            then.status(200).header("etag", "\"abc123\"").body(FAKE_INDEX_FILE);
+        });
+
+        let fetched = client.get_crate_versions("serde").await.unwrap();
+        first_fetch.assert();
+        first_fetch.delete();
+
+        let revalidated = mock_server.mock(|when, then| {
+            when.method("GET")
+                .path("/se/rd/serde")
+                .header("if-none-match", "\"abc123\"");
+            then.status(304);
+        });
+
+        let cached = client.get_crate_versions("serde").await.unwrap();
+
+        revalidated.assert();
+        assertor::assert_that!(cached.len()).is_equal_to(fetched.len());
+        assertor::assert_that!(cached[1].vers.clone()).is_equal_to("1.0.228".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_parse_dependencies_from_index_file() {
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+        let client = SparseIndexClient::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            cache_dir.path().to_path_buf(),
+        );
+
+        let mocked = mock_server.mock(|when, then| {
+            when.method("GET").path("/se/rd/serde");
+            then.status(200).body(FAKE_INDEX_FILE);
+        });
+
+        let with_dependencies = client.get_crate_version("serde", "1.0.227").await.unwrap();
+        let without_dependencies = client.get_crate_version("serde", "1.0.228").await.unwrap();
+
+        let dependencies = with_dependencies
+            .deps
+            .iter()
+            .map(|dependency| (dependency.name.as_str(), dependency.dependency_kind()))
+            .collect::<Vec<_>>();
+
+        mocked.assert_calls(2);
+        assertor::assert_that!(dependencies).is_equal_to(vec![
+            ("serde_derive", DependencyKind::Normal),
+            ("serde_derive", DependencyKind::Dev),
+        ]);
+        assertor::assert_that!(without_dependencies.deps.is_empty()).is_true();
+    }
+}
@@ -3,33 +3,49 @@
 
 use crate::core::models::CargoPackage;
 use crate::infra::caching::CacheManager;
-use crate::infra::networking::crates::registry::CratesDotIOClient;
-use anyhow::Context;
+use crate::infra::networking::crates::sparse::{IndexedCrateVersion, SparseIndexClient};
+use crate::infra::networking::http::HTTPClient;
+use anyhow::{Context, bail};
 use camino::Utf8PathBuf;
 use decompress::{Decompressor, ExtractOptsBuilder, decompressors};
+use sha2::{Digest, Sha256};
 use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// Served through a CDN, hence not subject to crates.io API rate limits
+// See https://doc.rust-lang.org/cargo/reference/registry-index.html#index-configuration
+pub static URL_CRATES_STATIC_DOWNLOADS: &str = "https://static.crates.io/crates";
 
 pub struct CrateArchiveDownloader {
-    cratesio_client: CratesDotIOClient,
+    downloads_base_url: String,
+    http_client: Arc<HTTPClient>,
+    sparse_index: SparseIndexClient,
     cache_manager: CacheManager,
 }
 
 impl CrateArchiveDownloader {
-    pub fn new(cratesio_client: CratesDotIOClient, cache_manager: CacheManager) -> Self {
+    pub fn new(
+        downloads_base_url: String,
+        http_client: Arc<HTTPClient>,
+        sparse_index: SparseIndexClient,
+        cache_manager: CacheManager,
+    ) -> Self {
         Self {
-            cratesio_client,
+            downloads_base_url,
+            http_client,
+            sparse_index,
             cache_manager,
         }
     }
 
-    pub async fn download_extract(&self, target_package: &CargoPackage) -> anyhow::Result<Utf8PathBuf> {
-        log::info!("[pollux.cargo] downloading package : {}", target_package.name);
-
-        let downloaded = self
-            .cratesio_client
-            .get_crate_tarball(&target_package.name, &target_package.version)
-            .await?;
+    pub async fn indexed_version(&self, target_package: &CargoPackage) -> anyhow::Result<IndexedCrateVersion> {
+        self.sparse_index
+            .get_crate_version(&target_package.name, &target_package.version)
+            .await
+    }
 
+    pub async fn download_extract(&self, target_package: &CargoPackage) -> anyhow::Result<Utf8PathBuf> {
         let project_dir = self.cache_manager.temporary_downloads_dir().join(&target_package.name);
 
         match fs::remove_dir_all(&project_dir) {
@@ -43,6 +59,46 @@ impl CrateArchiveDownloader {
             ),
         };
 
+        self.download_into(target_package, project_dir).await
+    }
+
+    pub async fn cached_sources(&self, target_package: &CargoPackage) -> anyhow::Result<Utf8PathBuf> {
+        let sources_dir = self
+            .cache_manager
+            .packages_cache_dir()
+            .join(&target_package.name)
+            .join(&target_package.version);
+
+        let extraction_path = format!("{}-{}", target_package.name, target_package.version);
+        let output_dir = sources_dir.join(extraction_path);
+
+        if output_dir.exists() {
+            log::info!("[pollux.cargo] reusing cached sources for {}", target_package);
+            let output_dir = Utf8PathBuf::try_from(output_dir).context("cannot get an utf-8 path")?;
+            return Ok(output_dir);
+        }
+
+        self.download_into(target_package, sources_dir).await
+    }
+
+    async fn download_into(&self, target_package: &CargoPackage, project_dir: PathBuf) -> anyhow::Result<Utf8PathBuf> {
+        log::info!("[pollux.cargo] downloading package : {}", target_package.name);
+
+        let downloaded = self.download_tarball(target_package).await?;
+
+        // The index is the source of truth cargo itself relies on when downloading crates
+        let indexed = self.indexed_version(target_package).await?;
+
+        let checksum = hex::encode(Sha256::digest(&downloaded));
+        if checksum != indexed.cksum {
+            bail!(
+                "pollux.cargo : checksum mismatch for {} (expected {}, got {})",
+                target_package,
+                indexed.cksum,
+                checksum
+            )
+        }
+
         fs::create_dir_all(&project_dir).context("failed to crate download folder")?;
         let tarball_path = project_dir.join("crate.tar.gz");
         fs::write(&tarball_path, downloaded).context("failed to save crate archive")?;
@@ -67,4 +123,73 @@ impl CrateArchiveDownloader {
         let output_dir = Utf8PathBuf::try_from(output_dir).context("cannot get an utf-8 path")?;
         Ok(output_dir)
     }
+
+    async fn download_tarball(&self, target_package: &CargoPackage) -> anyhow::Result<bytes::Bytes> {
+        let endpoint = format!(
+            "{}/{}/{}-{}.crate",
+            self.downloads_base_url, target_package.name, target_package.name, target_package.version
+        );
+
+        let response = self
+            .http_client
+            .get(&endpoint)
+            .send()
+            .await?
+            .error_for_status()
+            .context("pollux.cargo : failed to download crate tarball")?;
+
+        let bytes = response.bytes().await?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::CargoPackage;
+    use crate::infra::caching::CacheManager;
+    use crate::infra::networking::crates::sparse::SparseIndexClient;
+    use crate::infra::networking::crates::tarballs::CrateArchiveDownloader;
+    use crate::infra::networking::http::HTTP_CLIENT;
+    use assertor::{ResultAssertion, StringAssertion};
+    use httpmock::MockServer;
+    use temp_dir::TempDir;
+
+    #[tokio::test]
+    async fn should_download_tarballs_from_static_host_and_verify_checksums() {
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+
+        let sparse_index = SparseIndexClient::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            cache_dir.path().to_path_buf(),
+        );
+
+        let downloader = CrateArchiveDownloader::new(
+            mock_server.base_url(),
+            HTTP_CLIENT.clone(),
+            sparse_index,
+            CacheManager::get(),
+        );
+
+        let index_file = mock_server.mock(|when, then| {
+            when.method("GET").path("/ca/st/castaway");
+            then.status(200).body(
+                r#"{"name":"castaway","vers":"0.2.4","deps":[],"cksum":"dec551ab6e7578819132c713a93c022a05d60159dc86e7a7050223577484c55a","features":{},"yanked":false}"#,
+            );
+        });
+
+        let tarball = mock_server.mock(|when, then| {
+            when.method("GET").path("/castaway/castaway-0.2.4.crate");
+            then.status(200).body("tampered");
+        });
+
+        let cargo_package = CargoPackage::with("castaway", "0.2.4");
+        let downloaded = downloader.cached_sources(&cargo_package).await;
+
+        tarball.assert();
+        index_file.assert();
+        assertor::assert_that!(downloaded).is_err();
+        assertor::assert_that!(downloaded.unwrap_err().to_string()).contains("checksum mismatch");
+    }
 }
@@ -1,6 +1,7 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+use crate::infra::networking::ratelimiting::RateLimiter;
 use reqwest::header;
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_retry::RetryTransientMiddleware;
@@ -13,22 +14,57 @@ pub type HTTPClient = ClientWithMiddleware;
 pub static MAX_HTTP_RETRY_ATTEMPTS: u32 = 2;
 
 pub static HTTP_CLIENT: LazyLock<Arc<HTTPClient>> = LazyLock::new(|| {
-    let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+    let base_http_client = reqwest::Client::builder()
+        .default_headers(default_headers())
+        .timeout(Duration::from_secs(15))
+        .build()
+        .expect("cannot build HTTP client");
 
-    let mut headers = header::HeaderMap::new();
-    let user_agent = header::HeaderValue::from_str(&user_agent).expect("invalid header value");
-    headers.insert(header::USER_AGENT, user_agent);
+    with_retries(base_http_client)
+});
+
+// Large static files (e.g. database dumps) can't be downloaded within a total request timeout
+pub static DOWNLOADS_HTTP_CLIENT: LazyLock<Arc<HTTPClient>> = LazyLock::new(|| {
+    let base_http_client = reqwest::Client::builder()
+        .default_headers(default_headers())
+        .connect_timeout(Duration::from_secs(15))
+        .read_timeout(Duration::from_secs(60))
+        .build()
+        .expect("cannot build HTTP client");
+
+    with_retries(base_http_client)
+});
 
+pub fn rate_limited_http_client(rate_limiter: Arc<RateLimiter>) -> Arc<HTTPClient> {
     let base_http_client = reqwest::Client::builder()
-        .default_headers(headers)
+        .default_headers(default_headers())
         .timeout(Duration::from_secs(15))
         .build()
         .expect("cannot build HTTP client");
 
-    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(MAX_HTTP_RETRY_ATTEMPTS);
+    let rate_limited_http_client = reqwest_middleware::ClientBuilder::new(base_http_client)
+        .with(RetryTransientMiddleware::new_with_policy(retry_policy()))
+        .with_arc(rate_limiter)
+        .build();
+    Arc::new(rate_limited_http_client)
+}
+
+fn default_headers() -> header::HeaderMap {
+    let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+
+    let mut headers = header::HeaderMap::new();
+    let user_agent = header::HeaderValue::from_str(&user_agent).expect("invalid header value");
+    headers.insert(header::USER_AGENT, user_agent);
+    headers
+}
+
+fn retry_policy() -> ExponentialBackoff {
+    ExponentialBackoff::builder().build_with_max_retries(MAX_HTTP_RETRY_ATTEMPTS)
+}
 
+fn with_retries(base_http_client: reqwest::Client) -> Arc<HTTPClient> {
     let retrier_http_client = reqwest_middleware::ClientBuilder::new(base_http_client)
-        .with(RetryTransientMiddleware::new_with_policy(retry_policy))
+        .with(RetryTransientMiddleware::new_with_policy(retry_policy()))
         .build();
     Arc::new(retrier_http_client)
-});
+}
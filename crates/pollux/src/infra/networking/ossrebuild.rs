@@ -2,35 +2,54 @@
 // SPDX-License-Identifier: MIT
 
 use crate::core::interfaces::VeracityFactorCheck;
-use crate::core::models::CargoPackage;
+use crate::core::models::{CargoPackage, FactorCheck, ReproducibilityEvidence};
 use crate::infra::networking::http::HTTPClient;
+use crate::infra::networking::ossrebuild::listing::RebuildsCatalog;
 use anyhow::bail;
 use reqwest::StatusCode;
 use std::str::FromStr;
 use std::sync::Arc;
 use url::Url;
 
+pub mod attestations;
+pub mod listing;
+
 pub static URL_OSS_REBUILD_CRATES: &str = "https://storage.googleapis.com/google-rebuild-attestations/cratesio";
 
 pub struct OssRebuildChecker {
     base_url: String,
     http_client: Arc<HTTPClient>,
+    catalog: Option<RebuildsCatalog>,
 }
 
 impl OssRebuildChecker {
-    pub fn new(base_url: String, http_client: Arc<HTTPClient>) -> Self {
-        Self { base_url, http_client }
+    pub fn new(base_url: String, http_client: Arc<HTTPClient>, catalog: Option<RebuildsCatalog>) -> Self {
+        Self {
+            base_url,
+            http_client,
+            catalog,
+        }
     }
 }
 
 impl VeracityFactorCheck for OssRebuildChecker {
-    async fn execute(&self, crate_info: &CargoPackage) -> anyhow::Result<Option<Url>> {
+    type Evidence = ReproducibilityEvidence;
+
+    async fn execute(&self, crate_info: &CargoPackage) -> anyhow::Result<FactorCheck<ReproducibilityEvidence>> {
+        // Attestations themselves are still fetched, since their subjects back the digest cross-check
+        if let Some(catalog) = &self.catalog
+            && !catalog.snapshot().await?.is_attested(crate_info)
+        {
+            log::info!("[pollux.checker] {} not listed in oss-rebuild snapshot", crate_info);
+            return Ok(FactorCheck::missing());
+        }
+
         let endpoint = format!(
             "{}/{}/{}/{}-{}.crate/rebuild.intoto.jsonl",
             self.base_url, crate_info.name, crate_info.version, crate_info.name, crate_info.version
         );
 
-        let response = match self.http_client.head(&endpoint).send().await {
+        let response = match self.http_client.get(&endpoint).send().await {
             Ok(inner) => inner,
             Err(incoming) => {
                 log::info!("{}", incoming);
@@ -41,12 +60,14 @@ impl VeracityFactorCheck for OssRebuildChecker {
         if response.status() == StatusCode::OK {
             log::info!("[pollux.checker] found reproduced build for {}", crate_info);
             let url = Url::from_str(&endpoint)?;
-            return Ok(Some(url));
+            let attestations = response.text().await?;
+            let evidence = attestations::extract_evidence(url, &attestations)?;
+            return Ok(FactorCheck::verified(evidence));
         }
 
         if response.status() == StatusCode::NOT_FOUND {
             log::info!("[pollux.checker] reproduced build not found for {}", crate_info);
-            return Ok(None);
+            return Ok(FactorCheck::missing());
         }
 
         bail!(
@@ -59,16 +80,19 @@ impl VeracityFactorCheck for OssRebuildChecker {
 #[cfg(test)]
 mod tests {
     use crate::core::interfaces::VeracityFactorCheck;
-    use crate::core::models::CargoPackage;
+    use crate::core::models::{CargoPackage, FactorStatus, RebuildsSnapshotLocation};
     use crate::infra::networking::http::{HTTP_CLIENT, MAX_HTTP_RETRY_ATTEMPTS};
     use crate::infra::networking::ossrebuild::OssRebuildChecker;
-    use assertor::{OptionAssertion, ResultAssertion, StringAssertion};
+    use crate::infra::networking::ossrebuild::attestations::tests::fake_attestations;
+    use crate::infra::networking::ossrebuild::listing::{RebuildsCatalog, RebuildsLister};
+    use assertor::{BooleanAssertion, EqualityAssertion, OptionAssertion, ResultAssertion, StringAssertion};
     use httpmock::MockServer;
+    use temp_dir::TempDir;
 
     #[tokio::test]
     async fn should_check_rebuild_when_available() {
         let mock_server = MockServer::start();
-        let checker = OssRebuildChecker::new(mock_server.base_url(), HTTP_CLIENT.clone());
+        let checker = OssRebuildChecker::new(mock_server.base_url(), HTTP_CLIENT.clone(), None);
 
         let name = "castaway";
         let version = "0.2.2";
@@ -77,9 +101,11 @@ mod tests {
         let endpoint = format!("/{}/{}/{}-{}.crate/rebuild.intoto.jsonl", name, version, name, version);
 
         let mocked = mock_server.mock(|when, then| {
-            when.method("HEAD").path(endpoint);
+            when.method("GET").path(endpoint);
 
-            then.status(200).header("content-type", "text/plain; charset=UTF-8");
+            then.status(200)
+                .header("content-type", "text/plain; charset=UTF-8")
+                .body(fake_attestations(name, version));
         });
 
         let check = checker.execute(&crate_info).await.expect("fail the execute request");
@@ -87,13 +113,15 @@ mod tests {
         let path = format!("{}-{}.crate/rebuild.intoto.jsonl", crate_info.name, crate_info.version);
 
         mocked.assert();
-        assertor::assert_that!(check.unwrap().path()).contains(path);
+        let evidence = check.evidence.unwrap();
+        assertor::assert_that!(evidence.attestation_url.path()).contains(path);
+        assertor::assert_that!(evidence.build_strategy).is_equal_to(Some("cratesio_cargo_package".to_string()));
     }
 
     #[tokio::test]
     async fn should_check_rebuild_when_not_available() {
         let mock_server = MockServer::start();
-        let checker = OssRebuildChecker::new(mock_server.base_url(), HTTP_CLIENT.clone());
+        let checker = OssRebuildChecker::new(mock_server.base_url(), HTTP_CLIENT.clone(), None);
 
         let name = "castaway";
         let version = "0.1.0";
@@ -103,7 +131,7 @@ mod tests {
         let endpoint = format!("/{}/{}/{}-{}.crate/rebuild.intoto.jsonl", name, version, name, version);
 
         let mocked = mock_server.mock(|when, then| {
-            when.method("HEAD").path(endpoint);
+            when.method("GET").path(endpoint);
 
             then.status(404)
                 .header("content-type", "text/plain; charset=UTF-8")
@@ -113,13 +141,14 @@ mod tests {
         let check = checker.execute(&crate_info).await.unwrap();
 
         mocked.assert();
-        assertor::assert_that!(check).is_none()
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Missing);
+        assertor::assert_that!(check.evidence).is_none()
     }
 
     #[tokio::test]
     async fn should_not_check_rebuild_when_with_different_status_code() {
         let mock_server = MockServer::start();
-        let checker = OssRebuildChecker::new(mock_server.base_url(), HTTP_CLIENT.clone());
+        let checker = OssRebuildChecker::new(mock_server.base_url(), HTTP_CLIENT.clone(), None);
 
         let name = "castaway";
         let version = "0.2.4";
@@ -129,7 +158,7 @@ mod tests {
         let endpoint = format!("/{}/{}/{}-{}.crate/rebuild.intoto.jsonl", name, version, name, version);
 
         let mocked = mock_server.mock(|when, then| {
-            when.method("HEAD").path(endpoint);
+            when.method("GET").path(endpoint);
 
             then.status(503)
                 .header("content-type", "text/plain; charset=UTF-8")
@@ -141,4 +170,69 @@ mod tests {
         mocked.assert_calls(MAX_HTTP_RETRY_ATTEMPTS as usize + 1);
         assertor::assert_that!(check).is_err()
     }
+
+    #[tokio::test]
+    async fn should_skip_rebuild_lookup_when_not_listed_in_snapshot() {
+        let mock_server = MockServer::start();
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let snapshot_file = temp_dir.path().join("snapshot.json");
+
+        std::fs::write(
+            &snapshot_file,
+            r#"{ "listed_at": "2025-10-17T08:00:00Z", "attested": ["castaway@0.2.2"] }"#,
+        )
+        .expect("failed to write snapshot");
+
+        let lister = RebuildsLister::new(format!("{}/o", mock_server.base_url()), HTTP_CLIENT.clone());
+        let location = RebuildsSnapshotLocation::LocalFile(snapshot_file);
+        let catalog = RebuildsCatalog::new(location, lister, temp_dir.path().join("unused.json"));
+        let checker = OssRebuildChecker::new(mock_server.base_url(), HTTP_CLIENT.clone(), Some(catalog));
+
+        let mocked = mock_server.mock(|when, then| {
+            when.method("GET").path_includes("rebuild.intoto.jsonl");
+            then.status(404);
+        });
+
+        let crate_info = CargoPackage::with("castaway", "0.1.0");
+        let check = checker.execute(&crate_info).await.unwrap();
+
+        mocked.assert_calls(0);
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Missing);
+    }
+
+    #[tokio::test]
+    async fn should_fetch_attestations_for_crates_listed_in_snapshot() {
+        let mock_server = MockServer::start();
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let snapshot_file = temp_dir.path().join("snapshot.json");
+
+        std::fs::write(
+            &snapshot_file,
+            r#"{ "listed_at": "2025-10-17T08:00:00Z", "attested": ["castaway@0.2.2"] }"#,
+        )
+        .expect("failed to write snapshot");
+
+        let lister = RebuildsLister::new(format!("{}/o", mock_server.base_url()), HTTP_CLIENT.clone());
+        let location = RebuildsSnapshotLocation::LocalFile(snapshot_file);
+        let catalog = RebuildsCatalog::new(location, lister, temp_dir.path().join("unused.json"));
+        let checker = OssRebuildChecker::new(mock_server.base_url(), HTTP_CLIENT.clone(), Some(catalog));
+
+        let mocked = mock_server.mock(|when, then| {
+            when.method("GET").path_includes("rebuild.intoto.jsonl");
+            then.status(200).body(fake_attestations("castaway", "0.2.2"));
+        });
+
+        let crate_info = CargoPackage::with("castaway", "0.2.2");
+        let check = checker.execute(&crate_info).await.unwrap();
+
+        mocked.assert_calls(1);
+        assertor::assert_that!(check.status).is_equal_to(FactorStatus::Verified);
+
+        let Some(evidence) = check.evidence else {
+            panic!("expecting reproducibility evidence");
+        };
+
+        assertor::assert_that!(evidence.subjects.is_empty()).is_false();
+        assertor::assert_that!(evidence.build_strategy).is_equal_to(Some("cratesio_cargo_package".to_string()));
+    }
 }
@@ -0,0 +1,216 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::{AttestedSubject, ReproducibilityEvidence};
+use anyhow::{Context, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use url::Url;
+
+static IN_TOTO_PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+static BUILD_STRATEGY_BYPRODUCT: &str = "build.json";
+
+// See https://github.com/secure-systems-lab/dsse/blob/master/envelope.md
+#[derive(Debug, Deserialize)]
+struct DsseEnvelope {
+    #[serde(rename = "payloadType")]
+    payload_type: String,
+    payload: String,
+}
+
+// See https://github.com/in-toto/attestation/blob/main/spec/v1/statement.md
+#[derive(Debug, Deserialize)]
+struct InTotoStatement {
+    #[serde(default)]
+    subject: Vec<ResourceDescriptor>,
+    predicate: SlsaProvenance,
+}
+
+// See https://slsa.dev/spec/v1.0/provenance
+#[derive(Debug, Deserialize)]
+struct SlsaProvenance {
+    #[serde(rename = "runDetails")]
+    run_details: RunDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct RunDetails {
+    builder: Builder,
+    #[serde(default)]
+    byproducts: Vec<ResourceDescriptor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Builder {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResourceDescriptor {
+    name: Option<String>,
+    #[serde(default)]
+    digest: HashMap<String, String>,
+    content: Option<String>,
+}
+
+pub fn extract_evidence(attestation_url: Url, jsonl: &str) -> anyhow::Result<ReproducibilityEvidence> {
+    let mut evidence = ReproducibilityEvidence::new(attestation_url);
+
+    for line in jsonl.lines().filter(|line| !line.trim().is_empty()) {
+        let statement = decode_statement(line)?;
+
+        for subject in statement.subject {
+            let attested = AttestedSubject {
+                name: subject.name.unwrap_or_default(),
+                sha256: subject.digest.get("sha256").cloned(),
+            };
+
+            if !evidence.subjects.contains(&attested) {
+                evidence.subjects.push(attested);
+            }
+        }
+
+        let run_details = statement.predicate.run_details;
+
+        if evidence.builder_id.is_none() {
+            evidence.builder_id = Some(run_details.builder.id);
+        }
+
+        let build_strategy = run_details
+            .byproducts
+            .into_iter()
+            .find(|byproduct| byproduct.name.as_deref() == Some(BUILD_STRATEGY_BYPRODUCT))
+            .and_then(|byproduct| byproduct.content);
+
+        if let Some(encoded) = build_strategy {
+            let strategy = decode_build_strategy(&encoded)?;
+            evidence.build_strategy = strategy.as_object().and_then(|fields| fields.keys().next().cloned());
+            evidence.toolchain = find_toolchain(&strategy);
+        }
+    }
+
+    if evidence.subjects.is_empty() {
+        bail!("pollux.ossrebuild : no attested subjects found");
+    }
+
+    Ok(evidence)
+}
+
+fn decode_statement(line: &str) -> anyhow::Result<InTotoStatement> {
+    let envelope: DsseEnvelope = serde_json::from_str(line).context("pollux.ossrebuild : invalid DSSE envelope")?;
+
+    if envelope.payload_type != IN_TOTO_PAYLOAD_TYPE {
+        bail!(
+            "pollux.ossrebuild : unsupported payload type ({})",
+            envelope.payload_type
+        );
+    }
+
+    let payload = STANDARD.decode(envelope.payload)?;
+    let statement = serde_json::from_slice(&payload).context("pollux.ossrebuild : invalid in-toto statement")?;
+    Ok(statement)
+}
+
+fn decode_build_strategy(encoded: &str) -> anyhow::Result<Value> {
+    let decoded = STANDARD.decode(encoded)?;
+    let strategy = serde_json::from_slice(&decoded).context("pollux.ossrebuild : invalid build strategy")?;
+    Ok(strategy)
+}
+
+fn find_toolchain(strategy: &Value) -> Option<String> {
+    match strategy {
+        Value::Object(fields) => fields.iter().find_map(|(key, value)| match (key.as_str(), value) {
+            ("rust_version" | "rust_toolchain", Value::String(toolchain)) => Some(toolchain.clone()),
+            _ => find_toolchain(value),
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::models::AttestedSubject;
+    use crate::infra::networking::ossrebuild::attestations::extract_evidence;
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use std::str::FromStr;
+    use url::Url;
+
+    pub static FAKE_CRATE_DIGEST: &str = "641bbe6b0a0f4b6f2e4c1e3f8f5c9bf9b1b0b3a6ab8e8e0b6a7de5b0d7c4e1f2";
+
+    fn fake_envelope(statement: String) -> String {
+        let payload = STANDARD.encode(statement);
+        format!(
+            r#"{{"payloadType":"application/vnd.in-toto+json","payload":"{}","signatures":[]}}"#,
+            payload
+        )
+    }
+
+    pub fn fake_attestations(name: &str, version: &str) -> String {
+        let build_strategy = STANDARD.encode(
+            r#"{"cratesio_cargo_package":{"location":{"repo":"https://github.com/fake/fake","ref":"abcdef"},"rust_version":"1.85.0"}}"#,
+        );
+
+        let equivalence = format!(
+            r#"{{
+                "_type": "https://in-toto.io/Statement/v1",
+                "subject": [{{"name": "{name}-{version}.crate", "digest": {{"sha256": "{FAKE_CRATE_DIGEST}"}}}}],
+                "predicateType": "https://slsa.dev/provenance/v1",
+                "predicate": {{
+                    "buildDefinition": {{"buildType": "https://docs.oss-rebuild.dev/builds/ArtifactEquivalence@v0.1"}},
+                    "runDetails": {{"builder": {{"id": "https://docs.oss-rebuild.dev/hosts/Google"}}}}
+                }}
+            }}"#
+        );
+
+        let rebuild = format!(
+            r#"{{
+                "_type": "https://in-toto.io/Statement/v1",
+                "subject": [{{"name": "{name}-{version}.crate", "digest": {{"sha256": "{FAKE_CRATE_DIGEST}"}}}}],
+                "predicateType": "https://slsa.dev/provenance/v1",
+                "predicate": {{
+                    "buildDefinition": {{"buildType": "https://docs.oss-rebuild.dev/builds/Rebuild@v0.1"}},
+                    "runDetails": {{
+                        "builder": {{"id": "https://docs.oss-rebuild.dev/hosts/Google"}},
+                        "byproducts": [{{"name": "build.json", "content": "{build_strategy}"}}]
+                    }}
+                }}
+            }}"#
+        );
+
+        format!("{}\n{}\n", fake_envelope(equivalence), fake_envelope(rebuild))
+    }
+
+    #[test]
+    fn should_extract_evidence_from_attestations() {
+        let url = Url::from_str("https://shortn.ed/castaway-0.2.2.crate/rebuild.intoto.jsonl").unwrap();
+        let attestations = fake_attestations("castaway", "0.2.2");
+
+        let evidence = extract_evidence(url, &attestations).unwrap();
+
+        let expected_subjects = vec![AttestedSubject {
+            name: "castaway-0.2.2.crate".to_string(),
+            sha256: Some(FAKE_CRATE_DIGEST.to_string()),
+        }];
+
+        assertor::assert_that!(evidence.subjects).is_equal_to(expected_subjects);
+        assertor::assert_that!(evidence.build_strategy).is_equal_to(Some("cratesio_cargo_package".to_string()));
+        assertor::assert_that!(evidence.toolchain).is_equal_to(Some("1.85.0".to_string()));
+        assertor::assert_that!(evidence.builder_id)
+            .is_equal_to(Some("https://docs.oss-rebuild.dev/hosts/Google".to_string()));
+    }
+
+    #[test]
+    fn should_reject_malformed_attestations() {
+        let url = Url::from_str("https://shortn.ed/castaway-0.2.2.crate/rebuild.intoto.jsonl").unwrap();
+        let attestations = r#"{"payloadType":"application/vnd.in-toto+json","payload":"not-base64"}"#;
+
+        let evidence = extract_evidence(url, attestations);
+
+        assertor::assert_that!(evidence).is_err();
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::{CargoPackage, RebuildsSnapshotLocation};
+use crate::infra::networking::http::HTTPClient;
+use anyhow::Context;
+use jiff::{SignedDuration, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use url::Url;
+
+// See https://cloud.google.com/storage/docs/json_api/v1/objects/list
+pub static URL_OSS_REBUILD_LISTING: &str = "https://storage.googleapis.com/storage/v1/b/google-rebuild-attestations/o";
+
+static OSS_REBUILD_CRATES_PREFIX: &str = "cratesio/";
+static OSS_REBUILD_ATTESTATION_FILE: &str = "rebuild.intoto.jsonl";
+
+// oss-rebuild attests new releases continuously, so listings go stale quickly
+static SNAPSHOT_MAX_AGE: SignedDuration = SignedDuration::from_hours(24);
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BucketObjectsPage {
+    #[serde(default)]
+    items: Vec<BucketObject>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BucketObject {
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RebuildsSnapshot {
+    pub listed_at: Timestamp,
+    attested: HashSet<String>,
+}
+
+impl RebuildsSnapshot {
+    pub fn is_attested(&self, cargo_package: &CargoPackage) -> bool {
+        self.attested
+            .contains(&attested_key(&cargo_package.name, &cargo_package.version))
+    }
+
+    fn load(snapshot_file: &Path) -> anyhow::Result<Self> {
+        let serialized = fs::read(snapshot_file)
+            .with_context(|| format!("pollux.ossrebuild : cannot read snapshot ({:?})", snapshot_file))?;
+        let snapshot = serde_json::from_slice::<RebuildsSnapshot>(&serialized)
+            .with_context(|| format!("pollux.ossrebuild : invalid snapshot ({:?})", snapshot_file))?;
+        Ok(snapshot)
+    }
+
+    fn save(&self, snapshot_file: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = snapshot_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(snapshot_file, serde_json::to_vec(self)?).context("pollux.ossrebuild : cannot save snapshot")?;
+        Ok(())
+    }
+}
+
+fn attested_key(crate_name: &str, crate_version: &str) -> String {
+    format!("{}@{}", crate_name, crate_version)
+}
+
+// Objects follow the cratesio/<name>/<version>/<name>-<version>.crate/rebuild.intoto.jsonl layout
+fn parse_object_name(object_name: &str) -> Option<String> {
+    let attestation_path = object_name.strip_prefix(OSS_REBUILD_CRATES_PREFIX)?;
+    let segments = attestation_path.split('/').collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        [name, version, _, file_name] if *file_name == OSS_REBUILD_ATTESTATION_FILE => {
+            Some(attested_key(name, version))
+        },
+        _ => None,
+    }
+}
+
+pub struct RebuildsLister {
+    listing_url: String,
+    http_client: Arc<HTTPClient>,
+}
+
+impl RebuildsLister {
+    pub fn new(listing_url: String, http_client: Arc<HTTPClient>) -> Self {
+        Self {
+            listing_url,
+            http_client,
+        }
+    }
+
+    pub async fn list(&self) -> anyhow::Result<RebuildsSnapshot> {
+        let mut attested = HashSet::new();
+        let mut page_token = None::<String>;
+
+        loop {
+            let mut params = vec![
+                ("prefix", OSS_REBUILD_CRATES_PREFIX.to_string()),
+                ("fields", "items(name),nextPageToken".to_string()),
+                ("maxResults", "1000".to_string()),
+            ];
+
+            if let Some(token) = page_token.take() {
+                params.push(("pageToken", token));
+            }
+
+            let endpoint = Url::parse_with_params(&self.listing_url, &params)?;
+
+            let page = self
+                .http_client
+                .get(endpoint.as_str())
+                .send()
+                .await?
+                .error_for_status()
+                .context("pollux.ossrebuild : cannot list attestations")?
+                .json::<BucketObjectsPage>()
+                .await?;
+
+            attested.extend(page.items.iter().filter_map(|object| parse_object_name(&object.name)));
+
+            match page.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        log::info!("[pollux.ossrebuild] listed {} attested crate versions", attested.len());
+
+        let snapshot = RebuildsSnapshot {
+            listed_at: Timestamp::now(),
+            attested,
+        };
+
+        Ok(snapshot)
+    }
+}
+
+// Loads a snapshot once per run, either listing the bucket again or reading a saved one
+pub struct RebuildsCatalog {
+    location: RebuildsSnapshotLocation,
+    lister: RebuildsLister,
+    cached_snapshot_file: PathBuf,
+    snapshot: OnceCell<RebuildsSnapshot>,
+}
+
+impl RebuildsCatalog {
+    pub fn new(location: RebuildsSnapshotLocation, lister: RebuildsLister, cached_snapshot_file: PathBuf) -> Self {
+        Self {
+            location,
+            lister,
+            cached_snapshot_file,
+            snapshot: OnceCell::new(),
+        }
+    }
+
+    pub async fn snapshot(&self) -> anyhow::Result<&RebuildsSnapshot> {
+        self.snapshot.get_or_try_init(|| self.load_snapshot()).await
+    }
+
+    async fn load_snapshot(&self) -> anyhow::Result<RebuildsSnapshot> {
+        if let RebuildsSnapshotLocation::LocalFile(snapshot_file) = &self.location {
+            let snapshot = RebuildsSnapshot::load(snapshot_file)?;
+            log::info!(
+                "[pollux.ossrebuild] using snapshot listed at {} ({:?})",
+                snapshot.listed_at,
+                snapshot_file
+            );
+            return Ok(snapshot);
+        }
+
+        if let Ok(cached) = RebuildsSnapshot::load(&self.cached_snapshot_file)
+            && Timestamp::now().duration_since(cached.listed_at) < SNAPSHOT_MAX_AGE
+        {
+            log::info!("[pollux.ossrebuild] reusing snapshot listed at {}", cached.listed_at);
+            return Ok(cached);
+        }
+
+        let snapshot = self.lister.list().await?;
+        snapshot.save(&self.cached_snapshot_file)?;
+
+        log::info!("[pollux.ossrebuild] saved snapshot at {:?}", self.cached_snapshot_file);
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{CargoPackage, RebuildsSnapshotLocation};
+    use crate::infra::networking::http::HTTP_CLIENT;
+    use crate::infra::networking::ossrebuild::listing::{RebuildsCatalog, RebuildsLister, parse_object_name};
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use httpmock::MockServer;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_extract_crate_versions_from_object_names() {
+        let attestation = "cratesio/castaway/0.2.2/castaway-0.2.2.crate/rebuild.intoto.jsonl";
+        let other_file = "cratesio/castaway/0.2.2/castaway-0.2.2.crate/metadata.json";
+
+        assertor::assert_that!(parse_object_name(attestation)).is_equal_to(Some("castaway@0.2.2".to_string()));
+        assertor::assert_that!(parse_object_name(other_file)).is_equal_to(None);
+    }
+
+    #[tokio::test]
+    async fn should_list_attestations_across_pages_and_save_snapshot() {
+        let mock_server = MockServer::start();
+        let cache_dir = TempDir::new().expect("Cant create temp dir");
+        let snapshot_file = cache_dir.path().join("ossrebuild").join("snapshot.json");
+
+        let first_page = mock_server.mock(|when, then| {
+            when.method("GET").path("/o").query_param_missing("pageToken");
+            then.status(200).body(
+                r#"{
+                  "items": [{ "name": "cratesio/castaway/0.2.2/castaway-0.2.2.crate/rebuild.intoto.jsonl" }],
+                  "nextPageToken": "page-2"
+                }"#,
+            );
+        });
+
+        let second_page = mock_server.mock(|when, then| {
+            when.method("GET").path("/o").query_param("pageToken", "page-2");
+            then.status(200).body(
+                r#"{ "items": [{ "name": "cratesio/serde/1.0.228/serde-1.0.228.crate/rebuild.intoto.jsonl" }] }"#,
+            );
+        });
+
+        let lister = RebuildsLister::new(format!("{}/o", mock_server.base_url()), HTTP_CLIENT.clone());
+        let catalog = RebuildsCatalog::new(RebuildsSnapshotLocation::Latest, lister, snapshot_file.clone());
+
+        let snapshot = catalog.snapshot().await.unwrap();
+
+        first_page.assert();
+        second_page.assert();
+        assertor::assert_that!(snapshot.is_attested(&CargoPackage::with("castaway", "0.2.2"))).is_true();
+        assertor::assert_that!(snapshot.is_attested(&CargoPackage::with("serde", "1.0.228"))).is_true();
+        assertor::assert_that!(snapshot.is_attested(&CargoPackage::with("serde", "1.0.227"))).is_false();
+        assertor::assert_that!(snapshot_file.exists()).is_true();
+    }
+
+    #[tokio::test]
+    async fn should_answer_from_saved_snapshot_without_listing() {
+        let mock_server = MockServer::start();
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let snapshot_file = temp_dir.path().join("snapshot.json");
+
+        std::fs::write(
+            &snapshot_file,
+            r#"{ "listed_at": "2025-10-17T08:00:00Z", "attested": ["castaway@0.2.2"] }"#,
+        )
+        .expect("failed to write snapshot");
+
+        let listing = mock_server.mock(|when, then| {
+            when.method("GET").path("/o");
+            then.status(200).body("{}");
+        });
+
+        let lister = RebuildsLister::new(format!("{}/o", mock_server.base_url()), HTTP_CLIENT.clone());
+        let location = RebuildsSnapshotLocation::LocalFile(snapshot_file);
+        let catalog = RebuildsCatalog::new(location, lister, temp_dir.path().join("unused.json"));
+
+        let snapshot = catalog.snapshot().await.unwrap();
+
+        listing.assert_calls(0);
+        assertor::assert_that!(snapshot.is_attested(&CargoPackage::with("castaway", "0.2.2"))).is_true();
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::RateLimits;
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{Instant, sleep_until};
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+// Token bucket shared by every request against the same service, regardless of the caller
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    bucket: Mutex<TokenBucket>,
+}
+
+impl RateLimiter {
+    pub fn new(rate_limits: &RateLimits) -> Self {
+        let burst = rate_limits.burst.max(1) as f64;
+
+        Self {
+            requests_per_second: rate_limits.requests_per_second,
+            burst,
+            bucket: Mutex::new(TokenBucket {
+                tokens: burst,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    // Holding the lock while waiting keeps callers served in arrival order
+    pub async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        self.refill(&mut bucket);
+
+        if bucket.tokens < 1.0 {
+            let missing = 1.0 - bucket.tokens;
+            sleep_until(bucket.last_refill + Duration::from_secs_f64(missing / self.requests_per_second)).await;
+            self.refill(&mut bucket);
+        }
+
+        bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+    }
+
+    fn refill(&self, bucket: &mut TokenBucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.requests_per_second).min(self.burst);
+        bucket.last_refill = now;
+    }
+}
+
+// Placed after retries within the middleware stack, so every attempt takes its own token
+#[async_trait::async_trait]
+impl Middleware for RateLimiter {
+    async fn handle(
+        &self,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.acquire().await;
+        next.run(request, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{MAX_CRATESIO_BURST, RateLimits};
+    use crate::infra::networking::http::{MAX_HTTP_RETRY_ATTEMPTS, rate_limited_http_client};
+    use crate::infra::networking::ratelimiting::RateLimiter;
+    use assertor::BooleanAssertion;
+    use httpmock::MockServer;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn should_serve_burst_without_waiting() {
+        let rate_limits = RateLimits {
+            requests_per_second: 1.0,
+            burst: 3,
+        };
+
+        let rate_limiter = RateLimiter::new(&rate_limits);
+        let started = Instant::now();
+
+        for _ in 0..3 {
+            rate_limiter.acquire().await;
+        }
+
+        assertor::assert_that!(started.elapsed() < Duration::from_millis(50)).is_true();
+    }
+
+    #[tokio::test]
+    async fn should_pace_concurrent_callers_once_burst_is_exhausted() {
+        let rate_limits = RateLimits {
+            requests_per_second: 20.0,
+            burst: 1,
+        };
+
+        let rate_limiter = Arc::new(RateLimiter::new(&rate_limits));
+        let started = Instant::now();
+
+        let callers = (0..5)
+            .map(|_| {
+                let rate_limiter = rate_limiter.clone();
+                tokio::spawn(async move { rate_limiter.acquire().await })
+            })
+            .collect::<Vec<_>>();
+
+        for caller in callers {
+            caller.await.expect("caller should complete");
+        }
+
+        // One request served right away, then four more at twenty requests per second
+        assertor::assert_that!(started.elapsed() >= Duration::from_millis(190)).is_true();
+    }
+
+    #[tokio::test]
+    async fn should_keep_sustained_rate_once_burst_is_spent() {
+        let rate_limits = RateLimits {
+            requests_per_second: 20.0,
+            burst: MAX_CRATESIO_BURST,
+        };
+
+        let rate_limiter = RateLimiter::new(&rate_limits);
+        let started = Instant::now();
+
+        for _ in 0..MAX_CRATESIO_BURST {
+            rate_limiter.acquire().await;
+        }
+
+        let burst_elapsed = started.elapsed();
+
+        for _ in 0..4 {
+            rate_limiter.acquire().await;
+        }
+
+        // The burst is served right away, then four more requests at twenty requests per second
+        assertor::assert_that!(burst_elapsed < Duration::from_millis(50)).is_true();
+        assertor::assert_that!(started.elapsed() >= Duration::from_millis(190)).is_true();
+    }
+
+    #[tokio::test]
+    async fn should_take_one_token_per_retried_attempt() {
+        let rate_limits = RateLimits {
+            requests_per_second: 0.01,
+            burst: MAX_HTTP_RETRY_ATTEMPTS + 1,
+        };
+
+        let mock_server = MockServer::start();
+        let rate_limiter = Arc::new(RateLimiter::new(&rate_limits));
+        let http_client = rate_limited_http_client(rate_limiter.clone());
+
+        let mocked = mock_server.mock(|when, then| {
+            when.method("GET").path("/api/v1/crates");
+            then.status(503);
+        });
+
+        let endpoint = format!("{}/api/v1/crates", mock_server.base_url());
+        let _ = http_client.get(&endpoint).send().await;
+
+        // Every attempt drained the bucket, hence one more request must wait for a refill
+        let exhausted = tokio::time::timeout(Duration::from_millis(50), rate_limiter.acquire()).await;
+
+        mocked.assert_calls(MAX_HTTP_RETRY_ATTEMPTS as usize + 1);
+        assertor::assert_that!(exhausted.is_err()).is_true();
+    }
+}
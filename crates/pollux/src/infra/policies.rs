@@ -0,0 +1,99 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::policies::TrustPolicy;
+use anyhow::Context;
+use std::fs;
+use std::path::Path;
+
+pub fn load_policy(policy_file: &Path) -> anyhow::Result<TrustPolicy> {
+    let contents = fs::read_to_string(policy_file)
+        .with_context(|| format!("pollux.policies : cannot read policy file ({:?})", policy_file))?;
+    let policy = toml::from_str(&contents).context("pollux.policies : invalid policy file")?;
+    Ok(policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::VeracityFactor;
+    use crate::core::policies::{PolicyExemption, PolicyRequirement, PolicyScope, PolicyThreshold, TrustPolicy};
+    use crate::infra::policies::load_policy;
+    use assertor::{EqualityAssertion, ResultAssertion};
+    use jiff::civil::date;
+    use std::fs;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_load_policy_from_file() {
+        let policy_contents = r#"
+            [[requirements]]
+            factor = "trusted-publishing"
+            scope = "direct"
+
+            [[requirements]]
+            factor = "reproducible-builds"
+            crates = "serde*"
+
+            [[thresholds]]
+            factor = "trusted-publishing"
+            minimum_percentage = 30
+
+            [[exemptions]]
+            crate = "openssl-sys"
+            factor = "trusted-publishing"
+            reason = "Waiting for upstream adoption"
+            expires = "2025-12-31"
+        "#;
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let policy_file = temp_dir.path().join("pollux.toml");
+        fs::write(&policy_file, policy_contents).expect("failed to write policy file");
+
+        let policy = load_policy(&policy_file).unwrap();
+
+        let expected = TrustPolicy {
+            requirements: vec![
+                PolicyRequirement {
+                    factor: VeracityFactor::TrustedPublishing,
+                    scope: PolicyScope::Direct,
+                    crates: "*".to_string(),
+                },
+                PolicyRequirement {
+                    factor: VeracityFactor::ReproducibleBuilds,
+                    scope: PolicyScope::All,
+                    crates: "serde*".to_string(),
+                },
+            ],
+            thresholds: vec![PolicyThreshold {
+                factor: VeracityFactor::TrustedPublishing,
+                minimum_percentage: 30,
+            }],
+            exemptions: vec![PolicyExemption {
+                crate_name: "openssl-sys".to_string(),
+                version: None,
+                factor: Some(VeracityFactor::TrustedPublishing),
+                reason: "Waiting for upstream adoption".to_string(),
+                expires: date(2025, 12, 31),
+            }],
+        };
+
+        assertor::assert_that!(policy).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_reject_exemptions_without_expiration() {
+        let policy_contents = r#"
+            [[exemptions]]
+            crate = "openssl-sys"
+            reason = "Waiting for upstream adoption"
+        "#;
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let policy_file = temp_dir.path().join("pollux.toml");
+        fs::write(&policy_file, policy_contents).expect("failed to write policy file");
+
+        let policy = load_policy(&policy_file);
+
+        assertor::assert_that!(policy).is_err();
+    }
+}
@@ -1,11 +1,17 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+use crate::core::baselines::BaselineComparison;
 use crate::core::models::{
-    AnalysisResults, CargoPackage, CleanupScope, CrateVeracityChecks, EcosystemInquiringResults,
+    AnalysisResults, CargoPackage, CleanupScope, CrateVeracityChecks, CratesPublisher, DependencyPath, DependencyTier,
+    EcosystemInquiringResults, FactorCheck, FactorEvidence, FactorStatus, HistoryOutcome, PublishMethod,
+    ReproducibilityEvidence, VcsProvenanceEvidence, VeracityFactor, VersionSelection, WorkspaceAnalysisResults,
 };
+use crate::core::policies::PolicyViolation;
 use comfy_table::Table;
 use console::{StyledObject, style};
+use std::collections::BTreeSet;
+use std::path::Path;
 
 #[derive(Default)]
 pub struct ConsoleReporter {
@@ -28,23 +34,82 @@ impl ConsoleReporter {
         println!("Statistics : ");
         println!();
         println!("• total packages analysed : {}", self.cyan(statistics.total));
-        println!(
-            "• with trusted publishing attested : {}",
-            self.cyan(statistics.trusted_publishing)
-        );
-        println!(
-            "• with reproducible builds : {}",
-            self.cyan(statistics.reproducible_builds)
-        );
+
+        if statistics.unverifiable > 0 {
+            println!("• from unverifiable sources : {}", self.red(statistics.unverifiable));
+        }
+
+        for (factor, total) in &statistics.verified {
+            println!("• with {} : {}", self.verified_summary(*factor), self.cyan(total));
+        }
+
+        for (factor, total) in &statistics.mismatches {
+            if *total > 0 {
+                println!("• with {} : {}", self.mismatch_summary(*factor), self.red(total));
+            }
+        }
+
+        for (tier, tier_statistics) in &statistics.tiers {
+            println!();
+            println!("• {} dependencies : {}", tier, self.cyan(tier_statistics.total));
+
+            for (factor, total) in &tier_statistics.verified {
+                println!("  - with {} : {}", self.verified_summary(*factor), self.cyan(total));
+            }
+        }
+
+        if !statistics.publishers.is_empty() {
+            println!();
+            println!("Publishers : ");
+            println!();
+            println!(
+                "• distinct publishers with user tokens : {}",
+                self.cyan(statistics.publishers.len())
+            );
+
+            for (publisher, total) in &statistics.publishers {
+                println!("  - {} : {} packages", publisher, self.cyan(total));
+            }
+        }
+
+        if !results.unverifiable.is_empty() {
+            println!();
+            println!("Unverifiable sources : ");
+            println!();
+
+            for unverifiable_package in &results.unverifiable {
+                println!(
+                    "• {}{} ({})",
+                    unverifiable_package.cargo_package,
+                    self.dependency_position(results, &unverifiable_package.cargo_package),
+                    self.red(&unverifiable_package.source)
+                );
+            }
+        }
+
         println!();
         println!("Veracity factors : ");
         println!();
-        results
-            .outcomes
-            .iter()
+        // Direct dependencies come first, since those are the ones we can actually swap out
+        let mut outcomes = results.outcomes.iter().collect::<Vec<_>>();
+        outcomes.sort_by_key(|(package, _)| results.depth_of(package).unwrap_or(usize::MAX));
+
+        outcomes
+            .into_iter()
             .for_each(|(package, maybe_veracity_check)| match maybe_veracity_check {
                 Some(level) => {
-                    println!("• {} ({}) ", package, self.cyan(level));
+                    println!(
+                        "• {}{} ({}) ",
+                        package,
+                        self.dependency_position(results, package),
+                        self.cyan(level)
+                    );
+
+                    for check in level.factors.values() {
+                        if let Some(details) = self.factor_details(check) {
+                            println!("  - {}", details);
+                        }
+                    }
                 },
                 None => {
                     println!("• {} : {}", package, self.red("failed to analyse"));
@@ -54,25 +119,399 @@ impl ConsoleReporter {
         println!();
     }
 
+    pub fn report_workspace_outcomes(&self, results: &WorkspaceAnalysisResults) {
+        for member_results in &results.members {
+            println!();
+            println!("Workspace member : {}", self.cyan(&member_results.member));
+            self.report_analyser_outcomes(&member_results.results);
+        }
+
+        println!();
+        println!("Workspace rollup : ");
+        self.report_analyser_outcomes(&results.rollup);
+    }
+
+    pub fn report_version_resolved(&self, cargo_package: &CargoPackage, selection: VersionSelection) {
+        println!();
+        println!("Resolved {} as {}", self.cyan(cargo_package), selection);
+    }
+
     pub fn report_checker_started(&self, cargo_package: &CargoPackage) {
         println!();
         println!("Checking veracity factors for : {}", self.cyan(cargo_package));
         println!();
     }
 
-    pub fn report_checker_outcomes(&self, check: CrateVeracityChecks) {
+    pub fn report_checker_outcomes(&self, checks: CrateVeracityChecks) {
+        println!();
+
+        for (factor, check) in &checks.factors {
+            match factor {
+                VeracityFactor::TrustedPublishing => self.report_trusted_publishing(check),
+                VeracityFactor::ReproducibleBuilds => self.report_reproducibility(check),
+                VeracityFactor::RepositoryConsistency => self.report_repository_consistency(check),
+                VeracityFactor::VcsProvenance => self.report_vcs_provenance(check),
+            }
+        }
+
+        println!();
+    }
+
+    fn dependency_position(&self, results: &AnalysisResults, package: &CargoPackage) -> String {
+        match results.depth_of(package) {
+            Some(depth) => format!(" [{}, depth {}]", DependencyTier::from_depth(depth), depth),
+            None => String::new(),
+        }
+    }
+
+    fn verified_summary(&self, factor: VeracityFactor) -> &str {
+        match factor {
+            VeracityFactor::TrustedPublishing => "trusted publishing attested",
+            VeracityFactor::ReproducibleBuilds => "reproducible builds",
+            VeracityFactor::RepositoryConsistency => "consistent repositories",
+            VeracityFactor::VcsProvenance => "vcs provenance",
+        }
+    }
+
+    fn mismatch_summary(&self, factor: VeracityFactor) -> &str {
+        match factor {
+            VeracityFactor::TrustedPublishing => "trusted publishing downgrades",
+            VeracityFactor::ReproducibleBuilds => "rebuild digest mismatches",
+            VeracityFactor::RepositoryConsistency => "repository mismatches",
+            VeracityFactor::VcsProvenance => "vcs provenance mismatches",
+        }
+    }
+
+    fn factor_details(&self, check: &FactorCheck<FactorEvidence>) -> Option<String> {
+        match check.evidence.as_ref()? {
+            FactorEvidence::TrustedPublishing(evidence) => Some(format!(
+                "published from {} ({}) at commit {}",
+                evidence.repository, evidence.provider, evidence.commit_sha
+            )),
+            FactorEvidence::PublishingDowngrade(evidence) => Some(format!(
+                "trusted publishing downgrade : {} was published from {} ({})",
+                evidence.previous_version,
+                evidence.previous_publishing.repository,
+                evidence.previous_publishing.provider
+            )),
+            FactorEvidence::UserTokenPublishing(evidence) => {
+                Some(format!("published with a user token by {}", evidence.published_by))
+            },
+            FactorEvidence::Reproducibility(_) => None,
+            FactorEvidence::RepositoryConsistency(_) => Some(format!(
+                "declared repository : {}",
+                self.repository_consistency_outcome(check)
+            )),
+            FactorEvidence::VcsProvenance(evidence) => Some(format!(
+                "vcs provenance : {}",
+                self.vcs_provenance_outcome(Some(evidence))
+            )),
+        }
+    }
+
+    fn report_trusted_publishing(&self, check: &FactorCheck<FactorEvidence>) {
+        match &check.evidence {
+            Some(FactorEvidence::TrustedPublishing(evidence)) => {
+                println!("• trusted publishing evidence : {}", self.cyan(&evidence.workflow_run));
+                println!("  - publish method : {}", self.cyan(PublishMethod::TrustedPublishing));
+                println!("  - provider : {}", self.cyan(evidence.provider));
+                println!("  - repository : {}", self.cyan(&evidence.repository));
+                println!("  - commit : {}", self.cyan(&evidence.commit_sha));
+            },
+            Some(FactorEvidence::PublishingDowngrade(evidence)) => {
+                let previous = &evidence.previous_publishing;
+                println!(
+                    "• trusted publishing evidence : {} ({})",
+                    self.cyan("not found"),
+                    self.red("downgraded from previous releases")
+                );
+                self.report_token_publisher(evidence.published_by.as_ref());
+                println!("  - previous version : {}", self.cyan(&evidence.previous_version));
+                println!("  - previous workflow run : {}", self.cyan(&previous.workflow_run));
+                println!("  - previous provider : {}", self.cyan(previous.provider));
+                println!("  - previous repository : {}", self.cyan(&previous.repository));
+            },
+            Some(FactorEvidence::UserTokenPublishing(evidence)) => {
+                println!("• trusted publishing evidence : {}", self.cyan("not found"));
+                self.report_token_publisher(Some(&evidence.published_by));
+            },
+            _ => println!("• trusted publishing evidence : {}", self.cyan("not found")),
+        }
+    }
+
+    fn report_token_publisher(&self, published_by: Option<&CratesPublisher>) {
+        let not_informed = "not informed".to_string();
+        let publisher = published_by
+            .map(|publisher| publisher.to_string())
+            .unwrap_or(not_informed);
+        println!("  - publish method : {}", self.cyan(PublishMethod::UserToken));
+        println!("  - published by : {}", self.cyan(publisher));
+    }
+
+    fn report_reproducibility(&self, check: &FactorCheck<FactorEvidence>) {
+        match (check.status, &check.evidence) {
+            (FactorStatus::Verified, Some(FactorEvidence::Reproducibility(evidence))) => {
+                println!("• reproducibility evidence : {}", self.cyan(&evidence.attestation_url));
+                self.report_reproducibility_details(evidence);
+            },
+            (FactorStatus::Mismatch, Some(FactorEvidence::Reproducibility(evidence))) => {
+                println!(
+                    "• reproducibility evidence : {} ({})",
+                    self.cyan(&evidence.attestation_url),
+                    self.red("attested digest does not match")
+                );
+                self.report_reproducibility_details(evidence);
+            },
+            (_, _) => println!("• reproducibility evidence : {}", self.cyan("not found")),
+        }
+    }
+
+    fn report_repository_consistency(&self, check: &FactorCheck<FactorEvidence>) {
+        println!(
+            "• repository consistency : {}",
+            self.cyan(self.repository_consistency_outcome(check))
+        );
+
+        if let Some(FactorEvidence::RepositoryConsistency(evidence)) = &check.evidence {
+            let not_declared = "not declared".to_string();
+            let declared = evidence.declared_repository.as_ref().unwrap_or(&not_declared);
+            println!("  - declared repository : {}", self.cyan(declared));
+            println!(
+                "  - publishing repository : {}",
+                self.cyan(&evidence.publishing_repository)
+            );
+
+            if let Some(path_in_vcs) = &evidence.path_in_vcs {
+                println!("  - path in vcs : {}", self.cyan(path_in_vcs));
+            }
+        }
+    }
+
+    fn report_vcs_provenance(&self, check: &FactorCheck<FactorEvidence>) {
+        let evidence = match &check.evidence {
+            Some(FactorEvidence::VcsProvenance(evidence)) => Some(evidence),
+            _ => None,
+        };
+
+        println!(
+            "• vcs provenance : {}",
+            self.cyan(self.vcs_provenance_outcome(evidence))
+        );
+
+        if let Some(evidence) = evidence {
+            let not_informed = "not informed".to_string();
+            let path_in_vcs = evidence.path_in_vcs.as_ref().unwrap_or(&not_informed);
+            println!("  - vcs commit : {}", self.cyan(&evidence.commit_sha));
+            println!("  - path in vcs : {}", self.cyan(path_in_vcs));
+            println!("  - dirty : {}", self.cyan(evidence.dirty));
+        }
+    }
+
+    fn repository_consistency_outcome(&self, check: &FactorCheck<FactorEvidence>) -> &str {
+        match (check.status, &check.evidence) {
+            (FactorStatus::Verified, _) => "consistent",
+            (FactorStatus::Mismatch, _) => "mismatch",
+            (FactorStatus::Missing, Some(_)) => "undeclared",
+            (FactorStatus::Missing, None) => "not applicable",
+        }
+    }
+
+    fn vcs_provenance_outcome(&self, evidence: Option<&VcsProvenanceEvidence>) -> &str {
+        let Some(evidence) = evidence else {
+            return "not found";
+        };
+
+        match (evidence.dirty, evidence.matches_publishing_commit()) {
+            (true, _) => "dirty",
+            (false, Some(false)) => "commit mismatch",
+            (false, Some(true)) => "matches trusted publishing commit",
+            (false, None) => "clean",
+        }
+    }
+
+    fn report_reproducibility_details(&self, evidence: &ReproducibilityEvidence) {
+        let not_informed = "not informed".to_string();
+        let strategy = evidence.build_strategy.as_ref().unwrap_or(&not_informed);
+        let builder = evidence.builder_id.as_ref().unwrap_or(&not_informed);
+        let toolchain = evidence.toolchain.as_ref().unwrap_or(&not_informed);
+
+        println!("  - rebuild strategy : {}", self.cyan(strategy));
+        println!("  - rebuild builder : {}", self.cyan(builder));
+        println!("  - rebuild toolchain : {}", self.cyan(toolchain));
+
+        for subject in &evidence.subjects {
+            let digest = subject.sha256.as_ref().unwrap_or(&not_informed);
+            println!(
+                "  - attested subject : {} (sha256 = {})",
+                self.cyan(&subject.name),
+                digest
+            );
+        }
+    }
+
+    pub fn report_policy_violations(&self, violations: &[PolicyViolation]) {
+        println!();
+
+        if violations.is_empty() {
+            println!("{}", self.cyan("No policy violations found!"));
+            println!();
+            return;
+        }
+
+        println!("Policy violations : ");
+        println!();
+
+        for violation in violations {
+            println!("• {}", self.red(violation));
+        }
+
+        println!();
+    }
+
+    pub fn report_baseline_written(&self, baseline_file: &Path) {
+        println!("Baseline written at : {}", self.cyan(baseline_file.display()));
         println!();
+    }
 
-        if let Some(cratesio_link) = check.trusted_publishing_evidence {
-            println!("• trusted publishing evidence : {}", self.cyan(cratesio_link));
-        } else {
-            println!("• trusted publishing evidence : {}", self.cyan("not found"));
+    pub fn report_baseline_comparison(&self, comparison: &BaselineComparison) {
+        if !comparison.not_analysed.is_empty() {
+            println!("Not compared against baseline, since their analysis failed : ");
+            println!();
+
+            for cargo_package in &comparison.not_analysed {
+                println!("• {} : {}", cargo_package, self.red("failed to analyse"));
+            }
+
+            println!();
         }
 
-        if let Some(oss_rebuild_link) = check.reproducibility_evidence {
-            println!("• reproducibility evidence : {}", self.cyan(oss_rebuild_link));
-        } else {
-            println!("• reproducibility evidence : {}", self.cyan("not found"));
+        if comparison.regressions.is_empty() {
+            println!("{}", self.cyan("No regressions found against baseline!"));
+            println!();
+            return;
+        }
+
+        println!("Regressions against baseline : ");
+        println!();
+
+        for regression in &comparison.regressions {
+            println!("• {}", self.red(regression));
+        }
+
+        println!();
+    }
+
+    pub fn report_crate_history(&self, crate_name: &str, history: &[HistoryOutcome]) {
+        println!();
+        println!("Trust history for {} : ", self.cyan(crate_name));
+        println!();
+
+        let mut first_trusted_publishing = None;
+        let mut reproducible_versions = 0;
+
+        for (published, maybe_checks) in history {
+            let published_at = published
+                .published_at
+                .as_deref()
+                .and_then(|timestamp| timestamp.split('T').next())
+                .unwrap_or("unknown date");
+
+            let yanked = match published.yanked {
+                true => " [yanked]",
+                false => "",
+            };
+
+            let Some(checks) = maybe_checks else {
+                println!(
+                    "• {} ({}){} : {}",
+                    published.cargo_package.version,
+                    published_at,
+                    yanked,
+                    self.red("failed to analyse")
+                );
+                continue;
+            };
+
+            let adopted_trusted_publishing = first_trusted_publishing.is_none()
+                && checks
+                    .get(VeracityFactor::TrustedPublishing)
+                    .is_some_and(|check| check.is_verified());
+
+            if adopted_trusted_publishing {
+                first_trusted_publishing = Some(published);
+            }
+
+            if checks
+                .get(VeracityFactor::ReproducibleBuilds)
+                .is_some_and(|check| check.is_verified())
+            {
+                reproducible_versions += 1;
+            }
+
+            let milestone = match adopted_trusted_publishing {
+                true => " <- trusted publishing adopted",
+                false => "",
+            };
+
+            println!(
+                "• {} ({}){} : {}{}",
+                published.cargo_package.version,
+                published_at,
+                yanked,
+                self.cyan(checks),
+                milestone
+            );
+        }
+
+        println!();
+
+        match first_trusted_publishing {
+            Some(published) => println!(
+                "• trusted publishing first adopted at : {}",
+                self.cyan(&published.cargo_package.version)
+            ),
+            None => println!("• trusted publishing never adopted"),
+        }
+
+        println!(
+            "• versions with reproducible builds : {} out of {}",
+            self.cyan(reproducible_versions),
+            history.len()
+        );
+        println!();
+    }
+
+    pub fn report_dependency_paths(&self, crate_name: &str, paths: &[DependencyPath]) {
+        println!();
+        let total_paths = paths
+            .iter()
+            .fold(0u64, |total, path| total.saturating_add(path.total_paths));
+
+        println!(
+            "Found {} paths to {} through {} direct dependencies : ",
+            self.cyan(total_paths),
+            self.cyan(crate_name),
+            self.cyan(paths.len())
+        );
+
+        for (index, path) in paths.iter().enumerate() {
+            println!();
+            println!(
+                "• path #{} (shortest out of {} entering this way)",
+                index + 1,
+                self.cyan(path.total_paths)
+            );
+
+            for hop in &path.hops {
+                let trust_status = match (&hop.checks, hop.local, &hop.unverifiable_source) {
+                    (_, true, _) => self.cyan("local package".to_string()),
+                    (_, false, Some(source)) => self.red(format!("unverifiable, {}", source)),
+                    (Some(checks), false, None) => self.cyan(checks.to_string()),
+                    (None, false, None) => self.red("failed to analyse".to_string()),
+                };
+
+                println!("  - {} ({})", hop.cargo_package, trust_status);
+            }
         }
 
         println!();
@@ -107,27 +546,34 @@ impl ConsoleReporter {
         println!("Veracity factors : ");
         println!();
 
+        let factors = results
+            .outcomes
+            .iter()
+            .flat_map(|outcome| outcome.checks.factors.keys().copied())
+            .collect::<BTreeSet<_>>();
+
+        let mut header = vec!["Crate name", "Checked versions"];
+        header.extend(factors.iter().map(|factor| self.factor_title(*factor)));
+
         let mut table = Table::new();
-        table.set_header(vec![
-            "Crate name",
-            "Checked versions",
-            "Trusted Publishing",
-            "Reproducibility",
-        ]);
+        table.set_header(header);
         results.outcomes.iter().for_each(|outcome| {
-            let row = vec![
+            let mut row = vec![
                 outcome.cargo_package.name.as_str(),
                 outcome.cargo_package.version.as_str(),
-                match outcome.checks.trusted_publishing_evidence {
-                    None => "no",
-                    Some(_) => "yes",
-                },
-                match outcome.checks.reproducibility_evidence {
-                    None => "no",
-                    Some(_) => "yes",
-                },
             ];
 
+            row.extend(
+                factors
+                    .iter()
+                    .map(|factor| match outcome.checks.get(*factor).map(|check| check.status) {
+                        Some(FactorStatus::Verified) => "yes",
+                        Some(FactorStatus::Mismatch) if *factor == VeracityFactor::TrustedPublishing => "downgrade",
+                        Some(FactorStatus::Mismatch) => "mismatch",
+                        Some(FactorStatus::Missing) | None => "no",
+                    }),
+            );
+
             table.add_row(row);
         });
 
@@ -135,6 +581,15 @@ impl ConsoleReporter {
         println!();
     }
 
+    fn factor_title(&self, factor: VeracityFactor) -> &str {
+        match factor {
+            VeracityFactor::TrustedPublishing => "Trusted Publishing",
+            VeracityFactor::ReproducibleBuilds => "Reproducibility",
+            VeracityFactor::RepositoryConsistency => "Repository Consistency",
+            VeracityFactor::VcsProvenance => "VCS Provenance",
+        }
+    }
+
     fn cyan<T>(&self, what: T) -> StyledObject<T> {
         match self.use_colors {
             true => style(what).cyan(),
@@ -0,0 +1,380 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::graphs::DependencyGraph;
+use crate::core::models::{CargoPackage, PackageSource, ResolvedDependencies, UnverifiablePackage};
+use anyhow::{Context, bail};
+use packageurl::PackageUrl;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+// See https://cyclonedx.org/docs/1.6/json
+#[derive(Debug, Deserialize)]
+struct CycloneDxDocument {
+    #[serde(default)]
+    components: Vec<CycloneDxComponent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CycloneDxComponent {
+    purl: Option<String>,
+    #[serde(default)]
+    hashes: Vec<CycloneDxHash>,
+    #[serde(default)]
+    components: Vec<CycloneDxComponent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CycloneDxHash {
+    alg: String,
+    content: String,
+}
+
+// See https://spdx.github.io/spdx-spec/v2.3/package-information
+#[derive(Debug, Deserialize)]
+struct SpdxDocument {
+    #[serde(default)]
+    packages: Vec<SpdxPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxPackage {
+    #[serde(default)]
+    external_refs: Vec<SpdxExternalRef>,
+    #[serde(default)]
+    checksums: Vec<SpdxChecksum>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxExternalRef {
+    reference_type: String,
+    reference_locator: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxChecksum {
+    algorithm: String,
+    checksum_value: String,
+}
+
+struct SbomComponent {
+    purl: String,
+    sha256: Option<String>,
+}
+
+pub fn load_sbom(sbom_file: &Path) -> anyhow::Result<ResolvedDependencies> {
+    let contents =
+        fs::read(sbom_file).with_context(|| format!("pollux.sboms : cannot read SBOM file ({:?})", sbom_file))?;
+    let document = serde_json::from_slice::<serde_json::Value>(&contents).context("pollux.sboms : invalid JSON")?;
+
+    let components = if document.get("bomFormat").is_some() {
+        let cyclonedx = serde_json::from_value::<CycloneDxDocument>(document)
+            .context("pollux.sboms : invalid CycloneDX document")?;
+        cyclonedx_components(cyclonedx.components)
+    } else if document.get("spdxVersion").is_some() {
+        let spdx = serde_json::from_value::<SpdxDocument>(document).context("pollux.sboms : invalid SPDX document")?;
+        spdx_components(spdx.packages)
+    } else {
+        bail!("pollux.sboms : unsupported SBOM format, expected CycloneDX or SPDX JSON")
+    };
+
+    Ok(resolve_components(components))
+}
+
+fn cyclonedx_components(components: Vec<CycloneDxComponent>) -> Vec<SbomComponent> {
+    let mut flattened = vec![];
+
+    for component in components {
+        if let Some(purl) = component.purl {
+            let sha256 = component
+                .hashes
+                .into_iter()
+                .find(|hash| hash.alg == "SHA-256")
+                .map(|hash| hash.content);
+            flattened.push(SbomComponent { purl, sha256 });
+        }
+
+        // Components may nest other components, e.g. crates bundled within a binary
+        flattened.extend(cyclonedx_components(component.components));
+    }
+
+    flattened
+}
+
+fn spdx_components(packages: Vec<SpdxPackage>) -> Vec<SbomComponent> {
+    packages
+        .into_iter()
+        .filter_map(|package| {
+            let purl = package
+                .external_refs
+                .into_iter()
+                .find(|external_ref| external_ref.reference_type == "purl")?
+                .reference_locator;
+
+            let sha256 = package
+                .checksums
+                .into_iter()
+                .find(|checksum| checksum.algorithm == "SHA256")
+                .map(|checksum| checksum.checksum_value);
+
+            Some(SbomComponent { purl, sha256 })
+        })
+        .collect()
+}
+
+fn resolve_components(components: Vec<SbomComponent>) -> ResolvedDependencies {
+    let mut packages = vec![];
+    let mut unverifiable = vec![];
+
+    for component in components {
+        let Ok(purl) = PackageUrl::from_str(&component.purl) else {
+            log::warn!("[pollux.sboms] skipping invalid purl : {}", component.purl);
+            continue;
+        };
+
+        if purl.ty() != "cargo" {
+            continue;
+        }
+
+        let Some(version) = purl.version() else {
+            log::warn!("[pollux.sboms] skipping purl without version : {}", component.purl);
+            continue;
+        };
+
+        let cargo_package = CargoPackage::with_checksum(purl.name().to_string(), version.to_string(), component.sha256);
+
+        // The same component may be listed more than once, with or without its checksum
+        let duplicated = packages
+            .iter_mut()
+            .find(|existing| **existing == cargo_package)
+            .or(unverifiable
+                .iter_mut()
+                .map(|existing: &mut UnverifiablePackage| &mut existing.cargo_package)
+                .find(|existing| **existing == cargo_package));
+
+        if let Some(existing) = duplicated {
+            if existing.checksum.is_none() {
+                existing.checksum = cargo_package.checksum;
+            }
+
+            continue;
+        }
+
+        match package_source(&purl) {
+            Some(source) => unverifiable.push(UnverifiablePackage { cargo_package, source }),
+            None => packages.push(cargo_package),
+        }
+    }
+
+    // SBOMs list shipped components only, without relationships between them
+    ResolvedDependencies {
+        packages,
+        unverifiable,
+        graph: DependencyGraph::default(),
+    }
+}
+
+// Qualifiers pointing elsewhere mean the crate was not pulled from crates.io
+// See https://github.com/package-url/purl-spec/blob/main/PURL-SPECIFICATION.rst#known-qualifiers-keyvalue-pairs
+fn package_source(purl: &PackageUrl<'_>) -> Option<PackageSource> {
+    let qualifiers = purl.qualifiers();
+
+    if let Some(vcs_url) = qualifiers.get("vcs_url") {
+        let vcs_url = vcs_url.strip_prefix("git+").unwrap_or(vcs_url);
+
+        let source = match vcs_url.rsplit_once('@') {
+            Some((url, revision)) if !revision.contains('/') => PackageSource::Git {
+                url: url.to_string(),
+                revision: Some(revision.to_string()),
+            },
+            _ => PackageSource::Git {
+                url: vcs_url.to_string(),
+                revision: None,
+            },
+        };
+
+        return Some(source);
+    }
+
+    if let Some(download_url) = qualifiers.get("download_url")
+        && let Some(path) = download_url.strip_prefix("file://")
+    {
+        return Some(PackageSource::Path { path: path.to_string() });
+    }
+
+    qualifiers
+        .get("repository_url")
+        .map(|repository_url| PackageSource::Registry {
+            url: repository_url.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{CargoPackage, PackageSource, UnverifiablePackage};
+    use crate::infra::sboms::load_sbom;
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use std::fs;
+    use std::path::PathBuf;
+    use temp_dir::TempDir;
+
+    fn write_sbom(temp_dir: &TempDir, contents: &str) -> PathBuf {
+        let sbom_file = temp_dir.path().join("sbom.json");
+        fs::write(&sbom_file, contents).expect("failed to write SBOM file");
+        sbom_file
+    }
+
+    // Equality ignores checksums, hence they are compared on their own
+    fn checksums(packages: &[CargoPackage]) -> Vec<Option<String>> {
+        packages.iter().map(|package| package.checksum.clone()).collect()
+    }
+
+    #[test]
+    fn should_extract_cargo_packages_from_cyclonedx() {
+        let cyclonedx = r#"
+            {
+              "bomFormat": "CycloneDX",
+              "specVersion": "1.5",
+              "metadata": {
+                "component": { "name": "my-product", "purl": "pkg:cargo/my-product@1.0.0?download_url=file://." }
+              },
+              "components": [
+                {
+                  "name": "tokio",
+                  "purl": "pkg:cargo/tokio@1.47.1",
+                  "hashes": [{ "alg": "SHA-256", "content": "89e49afdadebb872d3145a5638b59eb0691ea23e46ca484037cfab3b76b95038" }],
+                  "components": [{ "name": "mio", "purl": "pkg:cargo/mio@1.0.4" }]
+                },
+                { "name": "my-helper", "purl": "pkg:cargo/my-helper@1.0.0?download_url=file://../helper" },
+                { "name": "openssl", "purl": "pkg:generic/openssl@3.5.0" }
+              ]
+            }
+        "#;
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let resolved = load_sbom(&write_sbom(&temp_dir, cyclonedx)).unwrap();
+
+        let expected_packages = vec![
+            CargoPackage::with_checksum(
+                "tokio".to_string(),
+                "1.47.1".to_string(),
+                Some("89e49afdadebb872d3145a5638b59eb0691ea23e46ca484037cfab3b76b95038".to_string()),
+            ),
+            CargoPackage::with("mio", "1.0.4"),
+        ];
+
+        let expected_unverifiable = vec![UnverifiablePackage {
+            cargo_package: CargoPackage::with("my-helper", "1.0.0"),
+            source: PackageSource::Path {
+                path: "../helper".to_string(),
+            },
+        }];
+
+        assertor::assert_that!(checksums(&resolved.packages)).is_equal_to(checksums(&expected_packages));
+        assertor::assert_that!(resolved.packages).is_equal_to(expected_packages);
+        assertor::assert_that!(resolved.unverifiable).is_equal_to(expected_unverifiable);
+    }
+
+    #[test]
+    fn should_deduplicate_components_keeping_known_checksums() {
+        let cyclonedx = r#"
+            {
+              "bomFormat": "CycloneDX",
+              "specVersion": "1.5",
+              "components": [
+                { "name": "tokio", "purl": "pkg:cargo/tokio@1.47.1" },
+                {
+                  "name": "tokio",
+                  "purl": "pkg:cargo/tokio@1.47.1",
+                  "hashes": [{ "alg": "SHA-256", "content": "89e49afdadebb872d3145a5638b59eb0691ea23e46ca484037cfab3b76b95038" }]
+                },
+                { "name": "tokio", "purl": "pkg:cargo/tokio@1.47.1" }
+              ]
+            }
+        "#;
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let resolved = load_sbom(&write_sbom(&temp_dir, cyclonedx)).unwrap();
+
+        let expected_packages = vec![CargoPackage::with_checksum(
+            "tokio".to_string(),
+            "1.47.1".to_string(),
+            Some("89e49afdadebb872d3145a5638b59eb0691ea23e46ca484037cfab3b76b95038".to_string()),
+        )];
+
+        assertor::assert_that!(checksums(&resolved.packages)).is_equal_to(checksums(&expected_packages));
+        assertor::assert_that!(resolved.packages).is_equal_to(expected_packages);
+    }
+
+    #[test]
+    fn should_extract_cargo_packages_from_spdx() {
+        let spdx = r#"
+            {
+              "spdxVersion": "SPDX-2.3",
+              "SPDXID": "SPDXRef-DOCUMENT",
+              "packages": [
+                {
+                  "SPDXID": "SPDXRef-Package-serde",
+                  "name": "serde",
+                  "checksums": [
+                    { "algorithm": "SHA256", "checksumValue": "9a8e94ea7f378bd32cbbd37198a4a91436180c5bb472411e48b5ec2e2124ae9e" }
+                  ],
+                  "externalRefs": [
+                    {
+                      "referenceCategory": "PACKAGE-MANAGER",
+                      "referenceType": "purl",
+                      "referenceLocator": "pkg:cargo/serde@1.0.228"
+                    }
+                  ]
+                },
+                {
+                  "SPDXID": "SPDXRef-Package-patched-tls",
+                  "name": "patched-tls",
+                  "externalRefs": [
+                    {
+                      "referenceCategory": "PACKAGE-MANAGER",
+                      "referenceType": "purl",
+                      "referenceLocator": "pkg:cargo/patched-tls@0.1.0?vcs_url=git%2Bhttps://github.com/example/patched-tls@3c1a5e7"
+                    }
+                  ]
+                },
+                { "SPDXID": "SPDXRef-Package-no-purl", "name": "no-purl" }
+              ]
+            }
+        "#;
+
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let resolved = load_sbom(&write_sbom(&temp_dir, spdx)).unwrap();
+
+        let expected_packages = vec![CargoPackage::with_checksum(
+            "serde".to_string(),
+            "1.0.228".to_string(),
+            Some("9a8e94ea7f378bd32cbbd37198a4a91436180c5bb472411e48b5ec2e2124ae9e".to_string()),
+        )];
+
+        let expected_unverifiable = vec![UnverifiablePackage {
+            cargo_package: CargoPackage::with("patched-tls", "0.1.0"),
+            source: PackageSource::Git {
+                url: "https://github.com/example/patched-tls".to_string(),
+                revision: Some("3c1a5e7".to_string()),
+            },
+        }];
+
+        assertor::assert_that!(checksums(&resolved.packages)).is_equal_to(checksums(&expected_packages));
+        assertor::assert_that!(resolved.packages).is_equal_to(expected_packages);
+        assertor::assert_that!(resolved.unverifiable).is_equal_to(expected_unverifiable);
+    }
+
+    #[test]
+    fn should_reject_unsupported_sbom_formats() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let loaded = load_sbom(&write_sbom(&temp_dir, r#"{ "name": "not-a-sbom" }"#));
+
+        assertor::assert_that!(loaded.is_err()).is_true();
+    }
+}
@@ -3,32 +3,48 @@
 
 use crate::core::analysers::combined::VeracityChecksAnalyser;
 use crate::core::analysers::standalone::{
-    BuildReproducibilityChecker, CachedDataChecker, CrateTrustedPublishingChecker,
+    BuildReproducibilityChecker, CachedDataChecker, CrateRepositoryConsistencyChecker, CrateTrustedPublishingChecker,
+    CrateVcsProvenanceChecker, VeracityFactorChecker,
 };
 use crate::infra::caching::CacheManager;
 use crate::infra::caching::analysis::AnalysedPackagesCache;
+use crate::infra::networking::crates::dbdump::CratesDatabaseDump;
+use crate::infra::networking::crates::provenance::{RepositoryConsistencyChecker, VcsProvenanceChecker};
 use crate::infra::networking::crates::registry::CratesDotIOClient;
 use crate::infra::networking::crates::resolvers::DependenciesResolver;
+use crate::infra::networking::crates::sparse::SparseIndexClient;
 use crate::infra::networking::crates::tarballs::CrateArchiveDownloader;
-use crate::infra::networking::crates::{OfficialCratesRegistryChecker, PopularCratesFetcher};
-use crate::infra::networking::http::HTTP_CLIENT;
+use crate::infra::networking::crates::{
+    CrateVersionResolver, CratesRegistryMetadata, OfficialCratesRegistryChecker, PopularCratesFetcher,
+};
+use crate::infra::networking::http::{DOWNLOADS_HTTP_CLIENT, HTTP_CLIENT, HTTPClient, rate_limited_http_client};
 use crate::infra::networking::ossrebuild::OssRebuildChecker;
+use crate::infra::networking::ossrebuild::listing::{RebuildsCatalog, RebuildsLister};
+use crate::infra::networking::ratelimiting::RateLimiter;
 use crate::infra::networking::{crates, ossrebuild};
 use crate::infra::reporting::console::ConsoleReporter;
 use crate::infra::reporting::html::HtmlReporter;
-use crate::pollux::Pollux;
 use crate::pollux::analyser::PolluxAnalyser;
 use crate::pollux::checker::PolluxChecker;
 use crate::pollux::cleaner::PolluxCleaner;
+use crate::pollux::explainer::PolluxExplainer;
 use crate::pollux::inquirer::PolluxInquirer;
+use crate::pollux::{Pollux, PolluxSettings};
+use std::sync::{Arc, OnceLock};
+
+// Every crates.io client must share the same pacing, otherwise limits apply per consumer
+static CRATESIO_HTTP_CLIENT: OnceLock<Arc<HTTPClient>> = OnceLock::new();
 
-pub static MILLIS_TO_WAIT_AFTER_RATE_LIMITED: u64 = 1100;
+fn cratesio_http_client(settings: &PolluxSettings) -> Arc<HTTPClient> {
+    CRATESIO_HTTP_CLIENT
+        .get_or_init(|| rate_limited_http_client(Arc::new(RateLimiter::new(&settings.rate_limits))))
+        .clone()
+}
 
-fn cratesio_client() -> CratesDotIOClient {
+fn cratesio_client(settings: &PolluxSettings) -> CratesDotIOClient {
     CratesDotIOClient::new(
         crates::registry::URL_OFFICIAL_CRATES_REGISTRY.to_string(),
-        HTTP_CLIENT.clone(),
-        MILLIS_TO_WAIT_AFTER_RATE_LIMITED,
+        cratesio_http_client(settings),
     )
 }
 
@@ -37,56 +53,136 @@ fn cached_checker() -> CachedDataChecker {
     CachedDataChecker::FileSystem(delegate)
 }
 
-fn trusted_publishing_checker() -> CrateTrustedPublishingChecker {
-    let delegate = OfficialCratesRegistryChecker::new(cratesio_client());
+fn sparse_index_client() -> SparseIndexClient {
+    SparseIndexClient::new(
+        crates::sparse::URL_CRATES_SPARSE_INDEX.to_string(),
+        HTTP_CLIENT.clone(),
+        CacheManager::get().sparse_index_cache_dir(),
+    )
+}
+
+fn crate_downloader() -> CrateArchiveDownloader {
+    CrateArchiveDownloader::new(
+        crates::tarballs::URL_CRATES_STATIC_DOWNLOADS.to_string(),
+        DOWNLOADS_HTTP_CLIENT.clone(),
+        sparse_index_client(),
+        CacheManager::get(),
+    )
+}
+
+fn cratesio_registry_metadata(settings: &PolluxSettings) -> CratesRegistryMetadata {
+    match &settings.database_dump {
+        Some(location) => CratesRegistryMetadata::DatabaseDump(CratesDatabaseDump::new(
+            location.clone(),
+            DOWNLOADS_HTTP_CLIENT.clone(),
+            CacheManager::get(),
+        )),
+        None => CratesRegistryMetadata::Api(cratesio_client(settings)),
+    }
+}
+
+fn trusted_publishing_checker(settings: &PolluxSettings) -> CrateTrustedPublishingChecker {
+    let delegate = OfficialCratesRegistryChecker::new(cratesio_registry_metadata(settings));
     CrateTrustedPublishingChecker::CratesOfficialRegistry(delegate)
 }
 
-fn reproducibility_checker() -> BuildReproducibilityChecker {
-    let delegate = OssRebuildChecker::new(ossrebuild::URL_OSS_REBUILD_CRATES.to_string(), HTTP_CLIENT.clone());
+fn rebuilds_catalog(settings: &PolluxSettings) -> Option<RebuildsCatalog> {
+    settings.rebuilds_snapshot.as_ref().map(|location| {
+        let lister = RebuildsLister::new(
+            ossrebuild::listing::URL_OSS_REBUILD_LISTING.to_string(),
+            HTTP_CLIENT.clone(),
+        );
+        RebuildsCatalog::new(location.clone(), lister, CacheManager::get().rebuilds_snapshot_file())
+    })
+}
+
+fn reproducibility_checker(settings: &PolluxSettings) -> BuildReproducibilityChecker {
+    let delegate = OssRebuildChecker::new(
+        ossrebuild::URL_OSS_REBUILD_CRATES.to_string(),
+        HTTP_CLIENT.clone(),
+        rebuilds_catalog(settings),
+    );
     BuildReproducibilityChecker::GoogleOssRebuild(delegate)
 }
 
-fn veracity_analyser() -> VeracityChecksAnalyser {
+fn repository_consistency_checker() -> CrateRepositoryConsistencyChecker {
+    let delegate = RepositoryConsistencyChecker::new(crate_downloader());
+    CrateRepositoryConsistencyChecker::CrateSources(delegate)
+}
+
+fn vcs_provenance_checker() -> CrateVcsProvenanceChecker {
+    let delegate = VcsProvenanceChecker::new(crate_downloader());
+    CrateVcsProvenanceChecker::CrateSources(delegate)
+}
+
+fn factor_checkers(settings: &PolluxSettings) -> Vec<VeracityFactorChecker> {
+    let mut checkers = vec![
+        VeracityFactorChecker::TrustedPublishing(trusted_publishing_checker(settings)),
+        VeracityFactorChecker::ReproducibleBuilds(reproducibility_checker(settings)),
+    ];
+
+    if settings.inspect_sources {
+        checkers.push(VeracityFactorChecker::RepositoryConsistency(
+            repository_consistency_checker(),
+        ));
+        checkers.push(VeracityFactorChecker::VcsProvenance(vcs_provenance_checker()));
+    }
+
+    checkers
+}
+
+fn veracity_analyser(settings: &PolluxSettings) -> VeracityChecksAnalyser {
     VeracityChecksAnalyser::new(
         cached_checker(),
-        trusted_publishing_checker(),
-        reproducibility_checker(),
+        factor_checkers(settings),
+        settings.rebuild_parallelism,
     )
 }
 
-fn dependencies_resolver() -> DependenciesResolver {
-    let downloader = CrateArchiveDownloader::new(cratesio_client(), CacheManager::get());
-    DependenciesResolver::new(downloader)
+fn dependencies_resolver(settings: &PolluxSettings) -> DependenciesResolver {
+    DependenciesResolver::new(
+        crate_downloader(),
+        CacheManager::get(),
+        settings.metadata_filters.clone(),
+        settings.locked,
+    )
 }
 
-fn popular_crates_fetcher() -> PopularCratesFetcher {
-    PopularCratesFetcher::new(cratesio_client())
+fn popular_crates_fetcher(settings: &PolluxSettings) -> PopularCratesFetcher {
+    PopularCratesFetcher::new(cratesio_client(settings))
 }
 
-fn pollux_analyser() -> PolluxAnalyser {
-    PolluxAnalyser::new(dependencies_resolver(), veracity_analyser())
+fn pollux_analyser(settings: &PolluxSettings) -> PolluxAnalyser {
+    PolluxAnalyser::new(dependencies_resolver(settings), veracity_analyser(settings))
+}
+
+fn pollux_checker(settings: &PolluxSettings) -> PolluxChecker {
+    PolluxChecker::new(
+        CrateVersionResolver::new(sparse_index_client(), cratesio_client(settings)),
+        veracity_analyser(settings),
+    )
 }
 
-fn pollux_checker() -> PolluxChecker {
-    PolluxChecker::new(veracity_analyser())
+fn pollux_explainer(settings: &PolluxSettings) -> PolluxExplainer {
+    PolluxExplainer::new(dependencies_resolver(settings), veracity_analyser(settings))
 }
 
 fn pollux_cleaner() -> PolluxCleaner {
     PolluxCleaner::new(CacheManager::get())
 }
 
-fn pollux_scrutinizer() -> PolluxInquirer {
-    PolluxInquirer::new(popular_crates_fetcher(), veracity_analyser())
+fn pollux_scrutinizer(settings: &PolluxSettings) -> PolluxInquirer {
+    PolluxInquirer::new(popular_crates_fetcher(settings), veracity_analyser(settings))
 }
 
-pub fn create_pollux(turnoff_colors: bool) -> Pollux {
+pub fn create_pollux(settings: &PolluxSettings) -> Pollux {
     Pollux::new(
         pollux_cleaner(),
-        pollux_analyser(),
-        pollux_checker(),
-        pollux_scrutinizer(),
-        ConsoleReporter::new(turnoff_colors),
+        pollux_analyser(settings),
+        pollux_checker(settings),
+        pollux_explainer(settings),
+        pollux_scrutinizer(settings),
+        ConsoleReporter::new(settings.turnoff_colors),
         HtmlReporter::default(),
     )
 }
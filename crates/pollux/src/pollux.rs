@@ -4,33 +4,73 @@
 pub mod analyser;
 pub mod checker;
 pub mod cleaner;
+pub mod explainer;
 pub mod inquirer;
 
-use crate::core::models::{CargoPackage, CleanupScope, InquireCoverage, InquireReportKind};
+use crate::core::baselines::Baseline;
+use crate::core::models::{
+    AnalysisResults, CargoPackage, CleanupScope, CrateReference, DatabaseDumpLocation, InquireCoverage,
+    InquireReportKind, MetadataFilters, RateLimits, RebuildsSnapshotLocation, RequestedVersion, ResolvedDependencies,
+};
+use crate::core::policies::TrustPolicy;
+use crate::infra::baselines;
 use crate::infra::reporting::console::ConsoleReporter;
 use crate::infra::reporting::html::HtmlReporter;
 use crate::pollux::PolluxTask::*;
+use crate::pollux::explainer::PolluxExplainer;
 use crate::pollux::inquirer::PolluxInquirer;
 use analyser::PolluxAnalyser;
+use anyhow::bail;
 use camino::Utf8PathBuf;
+use cargo_lock::Lockfile;
 use checker::PolluxChecker;
 use cleaner::PolluxCleaner;
+use jiff::Zoned;
+use semver::VersionReq;
 use std::path::PathBuf;
 
+pub enum BaselineUsage {
+    Skip,
+    Write(PathBuf),
+    Compare(Baseline),
+}
+
 pub enum PolluxTask {
-    AnalyseRustCrate(CargoPackage),
-    AnalyseRustProject(PathBuf),
-    CheckRustCrate(CargoPackage),
+    AnalyseRustCrate(CrateReference, BaselineUsage),
+    AnalyseRustProject(PathBuf, BaselineUsage),
+    AnalyseWorkspaceMembers(PathBuf, BaselineUsage),
+    AnalyseLockfile(Lockfile, BaselineUsage),
+    AnalyseSbom(ResolvedDependencies, BaselineUsage),
+    CheckRustCrate(CrateReference),
+    CheckCrateHistory(String, Option<VersionReq>),
+    VerifyRustCrate(CrateReference, TrustPolicy),
+    VerifyRustProject(PathBuf, TrustPolicy),
+    VerifyLockfile(Lockfile, TrustPolicy),
+    VerifySbom(ResolvedDependencies, TrustPolicy),
+    ExplainDependency(PathBuf, String, Option<String>),
     CleanupAnalysedData,
     CleanupPackageSource,
     CleanupEverything,
     InquirePopularCrates(InquireReportKind, InquireCoverage),
 }
 
+#[derive(Default)]
+pub struct PolluxSettings {
+    pub turnoff_colors: bool,
+    pub inspect_sources: bool,
+    pub metadata_filters: Option<MetadataFilters>,
+    pub locked: bool,
+    pub rate_limits: RateLimits,
+    pub database_dump: Option<DatabaseDumpLocation>,
+    pub rebuilds_snapshot: Option<RebuildsSnapshotLocation>,
+    pub rebuild_parallelism: usize,
+}
+
 pub struct Pollux {
     cleaner: PolluxCleaner,
     analyser: PolluxAnalyser,
     checker: PolluxChecker,
+    explainer: PolluxExplainer,
     inquirer: PolluxInquirer,
     console_reporter: ConsoleReporter,
     html_reporter: HtmlReporter,
@@ -41,6 +81,7 @@ impl Pollux {
         cleaner: PolluxCleaner,
         analyser: PolluxAnalyser,
         checker: PolluxChecker,
+        explainer: PolluxExplainer,
         inquirer: PolluxInquirer,
         console_reporter: ConsoleReporter,
         html_reporter: HtmlReporter,
@@ -49,6 +90,7 @@ impl Pollux {
             cleaner,
             analyser,
             checker,
+            explainer,
             inquirer,
             console_reporter,
             html_reporter,
@@ -57,9 +99,25 @@ impl Pollux {
 
     pub async fn execute(self, task: PolluxTask) -> anyhow::Result<()> {
         match task {
-            AnalyseRustProject(project_root) => self.analyse_rust_project(project_root).await?,
-            AnalyseRustCrate(cargo_package) => self.analyse_cargo_package(&cargo_package).await?,
-            CheckRustCrate(cargo_package) => self.check_individual_crate(&cargo_package).await?,
+            AnalyseRustProject(project_root, baseline) => self.analyse_rust_project(project_root, baseline).await?,
+            AnalyseWorkspaceMembers(project_root, baseline) => {
+                self.analyse_workspace_members(project_root, baseline).await?
+            },
+            AnalyseLockfile(lockfile, baseline) => self.analyse_lockfile(&lockfile, baseline).await?,
+            AnalyseSbom(dependencies, baseline) => self.analyse_sbom(dependencies, baseline).await?,
+            AnalyseRustCrate(crate_reference, baseline) => {
+                self.analyse_cargo_package(crate_reference, baseline).await?
+            },
+            CheckRustCrate(crate_reference) => self.check_individual_crate(crate_reference).await?,
+            CheckCrateHistory(crate_name, requirement) => self.check_crate_history(&crate_name, requirement).await?,
+            VerifyRustCrate(crate_reference, policy) => self.verify_cargo_package(crate_reference, policy).await?,
+            VerifyRustProject(project_root, policy) => self.verify_rust_project(project_root, policy).await?,
+            VerifyLockfile(lockfile, policy) => self.verify_lockfile(&lockfile, policy).await?,
+            VerifySbom(dependencies, policy) => self.verify_sbom(dependencies, policy).await?,
+            ExplainDependency(project_root, crate_name, crate_version) => {
+                self.explain_dependency(project_root, &crate_name, crate_version.as_deref())
+                    .await?
+            },
             CleanupEverything => self.cleanup_everything(),
             CleanupPackageSource => self.cleanup_packages(),
             CleanupAnalysedData => self.cleanup_analysed_data(),
@@ -69,28 +127,162 @@ impl Pollux {
         Ok(())
     }
 
-    async fn analyse_cargo_package(self, cargo_package: &CargoPackage) -> anyhow::Result<()> {
+    async fn resolve_crate_version(&self, crate_reference: CrateReference) -> anyhow::Result<CargoPackage> {
+        let selection = match crate_reference.version {
+            RequestedVersion::Exact(_) => None,
+            RequestedVersion::Selected(selection) => Some(selection),
+        };
+
+        let cargo_package = self.checker.resolve_version(crate_reference).await?;
+
+        if let Some(selection) = selection {
+            self.console_reporter.report_version_resolved(&cargo_package, selection);
+        }
+
+        Ok(cargo_package)
+    }
+
+    async fn analyse_cargo_package(
+        self,
+        crate_reference: CrateReference,
+        baseline: BaselineUsage,
+    ) -> anyhow::Result<()> {
+        let cargo_package = self.resolve_crate_version(crate_reference).await?;
         self.console_reporter.report_pollux_started();
-        let results = self.analyser.analyse_package(cargo_package).await?;
+        let results = self.analyser.analyse_package(&cargo_package).await?;
         self.console_reporter.report_analyser_outcomes(&results);
-        Ok(())
+        Self::apply_baseline(&self.console_reporter, &results, baseline)
     }
 
-    async fn analyse_rust_project(self, project_root: PathBuf) -> anyhow::Result<()> {
+    async fn analyse_rust_project(self, project_root: PathBuf, baseline: BaselineUsage) -> anyhow::Result<()> {
         self.console_reporter.report_pollux_started();
         let project_root = Utf8PathBuf::try_from(project_root.to_path_buf())?;
         let results = self.analyser.analyse_project(project_root).await?;
         self.console_reporter.report_analyser_outcomes(&results);
+        Self::apply_baseline(&self.console_reporter, &results, baseline)
+    }
+
+    async fn analyse_workspace_members(self, project_root: PathBuf, baseline: BaselineUsage) -> anyhow::Result<()> {
+        self.console_reporter.report_pollux_started();
+        let project_root = Utf8PathBuf::try_from(project_root.to_path_buf())?;
+        let results = self.analyser.analyse_workspace_members(project_root).await?;
+        self.console_reporter.report_workspace_outcomes(&results);
+        Self::apply_baseline(&self.console_reporter, &results.rollup, baseline)
+    }
+
+    async fn analyse_lockfile(self, lockfile: &Lockfile, baseline: BaselineUsage) -> anyhow::Result<()> {
+        self.console_reporter.report_pollux_started();
+        let results = self.analyser.analyse_lockfile(lockfile).await?;
+        self.console_reporter.report_analyser_outcomes(&results);
+        Self::apply_baseline(&self.console_reporter, &results, baseline)
+    }
+
+    async fn analyse_sbom(self, dependencies: ResolvedDependencies, baseline: BaselineUsage) -> anyhow::Result<()> {
+        self.console_reporter.report_pollux_started();
+        let results = self.analyser.analyse_sbom(dependencies).await?;
+        self.console_reporter.report_analyser_outcomes(&results);
+        Self::apply_baseline(&self.console_reporter, &results, baseline)
+    }
+
+    fn apply_baseline(
+        console_reporter: &ConsoleReporter,
+        results: &AnalysisResults,
+        baseline: BaselineUsage,
+    ) -> anyhow::Result<()> {
+        match baseline {
+            BaselineUsage::Skip => {},
+            BaselineUsage::Write(baseline_file) => {
+                baselines::write_baseline(&baseline_file, &Baseline::from_results(results))?;
+                console_reporter.report_baseline_written(&baseline_file);
+            },
+            BaselineUsage::Compare(existing) => {
+                let comparison = existing.compare(results);
+                console_reporter.report_baseline_comparison(&comparison);
+
+                if !comparison.regressions.is_empty() {
+                    bail!(
+                        "pollux.analyse : found {} regressions against baseline",
+                        comparison.regressions.len()
+                    );
+                }
+            },
+        }
+
         Ok(())
     }
 
-    async fn check_individual_crate(self, cargo_package: &CargoPackage) -> anyhow::Result<()> {
-        self.console_reporter.report_checker_started(cargo_package);
-        let check = self.checker.check_package(cargo_package).await?;
+    async fn verify_cargo_package(self, crate_reference: CrateReference, policy: TrustPolicy) -> anyhow::Result<()> {
+        let cargo_package = self.resolve_crate_version(crate_reference).await?;
+        self.console_reporter.report_pollux_started();
+        let results = self.analyser.analyse_package(&cargo_package).await?;
+        Self::enforce_policy(&self.console_reporter, &results, policy)
+    }
+
+    async fn verify_rust_project(self, project_root: PathBuf, policy: TrustPolicy) -> anyhow::Result<()> {
+        self.console_reporter.report_pollux_started();
+        let project_root = Utf8PathBuf::try_from(project_root.to_path_buf())?;
+        let results = self.analyser.analyse_project(project_root).await?;
+        Self::enforce_policy(&self.console_reporter, &results, policy)
+    }
+
+    async fn verify_lockfile(self, lockfile: &Lockfile, policy: TrustPolicy) -> anyhow::Result<()> {
+        self.console_reporter.report_pollux_started();
+        let results = self.analyser.analyse_lockfile(lockfile).await?;
+        Self::enforce_policy(&self.console_reporter, &results, policy)
+    }
+
+    async fn verify_sbom(self, dependencies: ResolvedDependencies, policy: TrustPolicy) -> anyhow::Result<()> {
+        self.console_reporter.report_pollux_started();
+        let results = self.analyser.analyse_sbom(dependencies).await?;
+        Self::enforce_policy(&self.console_reporter, &results, policy)
+    }
+
+    fn enforce_policy(
+        console_reporter: &ConsoleReporter,
+        results: &AnalysisResults,
+        policy: TrustPolicy,
+    ) -> anyhow::Result<()> {
+        let violations = policy.evaluate(results, Zoned::now().date());
+        console_reporter.report_policy_violations(&violations);
+
+        if !violations.is_empty() {
+            bail!("pollux.verify : found {} policy violations", violations.len());
+        }
+
+        Ok(())
+    }
+
+    async fn check_individual_crate(self, crate_reference: CrateReference) -> anyhow::Result<()> {
+        let cargo_package = self.resolve_crate_version(crate_reference).await?;
+        self.console_reporter.report_checker_started(&cargo_package);
+        let check = self.checker.check_package(&cargo_package).await?;
         self.console_reporter.report_checker_outcomes(check);
         Ok(())
     }
 
+    async fn check_crate_history(self, crate_name: &str, requirement: Option<VersionReq>) -> anyhow::Result<()> {
+        self.console_reporter.report_pollux_started();
+        let history = self.checker.check_history(crate_name, requirement.as_ref()).await?;
+        self.console_reporter.report_crate_history(crate_name, &history);
+        Ok(())
+    }
+
+    async fn explain_dependency(
+        self,
+        project_root: PathBuf,
+        crate_name: &str,
+        crate_version: Option<&str>,
+    ) -> anyhow::Result<()> {
+        self.console_reporter.report_pollux_started();
+        let project_root = Utf8PathBuf::try_from(project_root.to_path_buf())?;
+        let paths = self
+            .explainer
+            .explain_dependency(project_root, crate_name, crate_version)
+            .await?;
+        self.console_reporter.report_dependency_paths(crate_name, &paths);
+        Ok(())
+    }
+
     fn cleanup_everything(self) {
         self.cleaner.cleanup_everything();
         self.console_reporter.report_cleaning_finished(CleanupScope::Everything)
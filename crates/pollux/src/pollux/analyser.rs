@@ -3,11 +3,15 @@
 
 use crate::core::analysers::combined::VeracityChecksAnalyser;
 use crate::core::interfaces::CrateVeracityAnalysis;
-use crate::core::models::{AnalysisOutcome, AnalysisResults, CargoPackage, StatisticsForPackages};
+use crate::core::models::{
+    AnalysisOutcome, AnalysisResults, CargoPackage, DependencyTier, MemberAnalysisResults, ResolvedDependencies,
+    StatisticsForPackages, TierStatistics, UnverifiablePackage, VeracityFactor, WorkspaceAnalysisResults,
+};
 use crate::infra::networking::crates::resolvers::DependenciesResolver;
-use crate::ioc::MILLIS_TO_WAIT_AFTER_RATE_LIMITED;
 use camino::Utf8PathBuf;
+use cargo_lock::Lockfile;
 use ractor::{Actor, ActorProcessingErr, ActorRef, RpcReplyPort};
+use std::collections::{BTreeMap, HashMap};
 
 pub enum AnalyserMessage {
     AnalysePackage(CargoPackage),
@@ -28,33 +32,203 @@ impl PolluxAnalyser {
     }
 
     pub async fn analyse_project(self, project_path: Utf8PathBuf) -> anyhow::Result<AnalysisResults> {
-        let cargo_packages = self
+        let dependencies = self
             .dependencies_resolver
             .resolve_for_local_project(project_path)
             .await?;
-        self.analyse_packages(cargo_packages).await
+        self.analyse_packages(dependencies).await
     }
 
     pub async fn analyse_package(self, cargo_package: &CargoPackage) -> anyhow::Result<AnalysisResults> {
-        let cargo_packages = self
+        let dependencies = self
             .dependencies_resolver
             .resolve_for_crate_package(cargo_package)
             .await?;
-        self.analyse_packages(cargo_packages).await
+        self.analyse_packages(dependencies).await
     }
 
-    async fn analyse_packages(self, cargo_packages: Vec<CargoPackage>) -> anyhow::Result<AnalysisResults> {
-        let total_project_packages = cargo_packages.len() as u64;
+    pub async fn analyse_lockfile(self, lockfile: &Lockfile) -> anyhow::Result<AnalysisResults> {
+        let dependencies = self.dependencies_resolver.resolve_for_lockfile(lockfile).await?;
+        self.analyse_packages(dependencies).await
+    }
+
+    pub async fn analyse_sbom(self, dependencies: ResolvedDependencies) -> anyhow::Result<AnalysisResults> {
+        self.analyse_packages(dependencies).await
+    }
+
+    pub async fn analyse_workspace_members(
+        self,
+        project_path: Utf8PathBuf,
+    ) -> anyhow::Result<WorkspaceAnalysisResults> {
+        let dependencies = self
+            .dependencies_resolver
+            .resolve_for_local_project(project_path)
+            .await?;
+
+        let mut members = dependencies.graph.local_packages().to_vec();
+        members.sort_by(|first, second| first.name.cmp(&second.name));
+
+        let member_depths = members
+            .into_iter()
+            .map(|member| {
+                let depths = dependencies.graph.depths_from(std::slice::from_ref(&member));
+                (member, depths)
+            })
+            .collect::<Vec<_>>();
+
+        // Every package is analysed once, then outcomes are split across member closures
+        let factors = self.veracity_analyser.factors();
+        let rollup = self.analyse_packages(dependencies).await?;
+
+        let members = member_depths
+            .into_iter()
+            .map(|(member, depths)| MemberAnalysisResults {
+                member,
+                results: member_results(&factors, &rollup, &depths),
+            })
+            .collect();
+
+        Ok(WorkspaceAnalysisResults { members, rollup })
+    }
+
+    async fn analyse_packages(self, dependencies: ResolvedDependencies) -> anyhow::Result<AnalysisResults> {
+        let total_project_packages = dependencies.packages.len() as u64;
+        let depths = dependencies.graph.depths();
+        self.veracity_analyser.prefetch_rebuilds(&dependencies.packages).await;
+
+        // crates.io checks remain serialised, one package at a time
         let (actor, _) = Actor::spawn(None, self, total_project_packages).await?;
 
-        for package in cargo_packages {
+        for package in dependencies.packages {
             actor.cast(AnalyserMessage::AnalysePackage(package))?
         }
 
-        let max_timeout = MILLIS_TO_WAIT_AFTER_RATE_LIMITED * 2 * total_project_packages;
-        let results = ractor::call_t!(actor, AnalyserMessage::AggregateResults, max_timeout)?;
-        Ok(results)
+        // Packages take as many crates.io calls as their cache state and sources inspection demand,
+        // while every single request is bounded by HTTP timeouts, hence results are awaited without one
+        let results = ractor::call!(actor, AnalyserMessage::AggregateResults)?;
+        Ok(with_dependency_graph_insights(
+            results,
+            dependencies.unverifiable,
+            depths,
+        ))
+    }
+}
+
+fn member_results(
+    factors: &[VeracityFactor],
+    rollup: &AnalysisResults,
+    member_depths: &HashMap<CargoPackage, usize>,
+) -> AnalysisResults {
+    let outcomes = rollup
+        .outcomes
+        .iter()
+        .filter(|(package, _)| member_depths.contains_key(package))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let unverifiable = rollup
+        .unverifiable
+        .iter()
+        .filter(|unverifiable| member_depths.contains_key(&unverifiable.cargo_package))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let depths = member_depths.clone();
+
+    let results = AnalysisResults {
+        statistics: aggregate_statistics(factors, &outcomes),
+        outcomes,
+        unverifiable: vec![],
+        depths: HashMap::new(),
+    };
+
+    with_dependency_graph_insights(results, unverifiable, depths)
+}
+
+fn with_dependency_graph_insights(
+    mut results: AnalysisResults,
+    unverifiable: Vec<UnverifiablePackage>,
+    depths: HashMap<CargoPackage, usize>,
+) -> AnalysisResults {
+    results.statistics.tiers = tier_statistics(&results.outcomes, &unverifiable, &depths);
+
+    // Unverifiable packages are still part of the build, hence they count towards totals
+    results.statistics.total += unverifiable.len();
+    results.statistics.unverifiable = unverifiable.len();
+    results.unverifiable = unverifiable;
+    results.depths = depths;
+    results
+}
+
+fn aggregate_statistics(factors: &[VeracityFactor], outcomes: &[AnalysisOutcome]) -> StatisticsForPackages {
+    let mut verified = factors.iter().map(|factor| (*factor, 0)).collect::<BTreeMap<_, _>>();
+    let mut mismatches = factors.iter().map(|factor| (*factor, 0)).collect::<BTreeMap<_, _>>();
+    let mut publishers = BTreeMap::new();
+
+    for (package, checks) in outcomes {
+        let Some(existing) = checks else {
+            continue;
+        };
+
+        if let Some(publisher) = existing.publisher() {
+            *publishers.entry(publisher.clone()).or_default() += 1;
+        }
+
+        for (factor, check) in existing.factors.iter() {
+            if check.is_verified() {
+                *verified.entry(*factor).or_default() += 1;
+            }
+
+            if check.is_mismatch() {
+                log::warn!("[pollux.actor] {} mismatch for : {}", factor, package);
+                *mismatches.entry(*factor).or_default() += 1;
+            }
+        }
+    }
+
+    StatisticsForPackages {
+        total: outcomes.len(),
+        unverifiable: 0,
+        verified,
+        mismatches,
+        publishers,
+        tiers: BTreeMap::new(),
+    }
+}
+
+fn tier_statistics(
+    outcomes: &[AnalysisOutcome],
+    unverifiable: &[UnverifiablePackage],
+    depths: &HashMap<CargoPackage, usize>,
+) -> BTreeMap<DependencyTier, TierStatistics> {
+    let mut tiers = BTreeMap::<DependencyTier, TierStatistics>::new();
+
+    for (package, checks) in outcomes {
+        let Some(depth) = depths.get(package) else {
+            continue;
+        };
+
+        let tier_statistics = tiers.entry(DependencyTier::from_depth(*depth)).or_default();
+        tier_statistics.total += 1;
+
+        for (factor, check) in checks.iter().flat_map(|existing| existing.factors.iter()) {
+            let verified = tier_statistics.verified.entry(*factor).or_default();
+
+            if check.is_verified() {
+                *verified += 1;
+            }
+        }
+    }
+
+    for unverifiable_package in unverifiable {
+        let Some(depth) = depths.get(&unverifiable_package.cargo_package) else {
+            continue;
+        };
+
+        tiers.entry(DependencyTier::from_depth(*depth)).or_default().total += 1;
     }
+
+    tiers
 }
 
 impl Actor for PolluxAnalyser {
@@ -82,42 +256,14 @@ impl Actor for PolluxAnalyser {
             AnalyserMessage::AggregateResults(reply) => {
                 log::info!("[pollux.actor] computing aggregated results for processed packages");
 
-                let mut total_analysed_packages = 0;
-                let mut with_trusted_publishing = 0;
-                let mut with_reproducible_builds = 0;
-
-                for (package, checks) in outcomes.iter() {
-                    total_analysed_packages += 1;
-
-                    if let Some(existing) = checks {
-                        match (
-                            &existing.trusted_publishing_evidence,
-                            &existing.reproducibility_evidence,
-                        ) {
-                            (Some(_), Some(_)) => {
-                                with_reproducible_builds += 1;
-                                with_trusted_publishing += 1
-                            },
-
-                            (Some(_), None) => with_trusted_publishing += 1,
-
-                            (None, Some(_)) => with_reproducible_builds += 1,
-                            (None, None) => {
-                                log::info!("[pollux.actor] no stats for : {}", package);
-                            },
-                        }
-                    }
-                }
-
-                let statistics = StatisticsForPackages {
-                    total: total_analysed_packages,
-                    trusted_publishing: with_trusted_publishing,
-                    reproducible_builds: with_reproducible_builds,
-                };
+                let factors = self.veracity_analyser.factors();
+                let statistics = aggregate_statistics(&factors, outcomes);
 
                 let results = AnalysisResults {
                     statistics,
                     outcomes: outcomes.clone(),
+                    unverifiable: vec![],
+                    depths: HashMap::new(),
                 };
 
                 if reply.send(results).is_err() {
@@ -129,3 +275,160 @@ impl Actor for PolluxAnalyser {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::graphs::DependencyGraph;
+    use crate::core::models::{
+        AnalysisOutcome, AnalysisResults, CargoPackage, CrateVeracityChecks, DependencyTier, FactorCheck, FactorStatus,
+        PackageSource, TierStatistics, UnverifiablePackage, VeracityFactor,
+    };
+    use crate::pollux::analyser::{aggregate_statistics, member_results, tier_statistics};
+    use assertor::{BooleanAssertion, EqualityAssertion};
+    use std::collections::{BTreeMap, HashMap};
+
+    fn analysed(name: &str, trusted_publishing: FactorStatus) -> AnalysisOutcome {
+        let check = FactorCheck {
+            status: trusted_publishing,
+            evidence: None,
+        };
+
+        let checks = CrateVeracityChecks::from([(VeracityFactor::TrustedPublishing, check)]);
+        (CargoPackage::with(name, "1.0.0"), Some(checks))
+    }
+
+    fn from_git(name: &str) -> UnverifiablePackage {
+        UnverifiablePackage {
+            cargo_package: CargoPackage::with(name, "0.1.0"),
+            source: PackageSource::Git {
+                url: format!("https://github.com/acme/{}", name),
+                revision: None,
+            },
+        }
+    }
+
+    #[test]
+    fn should_break_statistics_down_by_dependency_tier() {
+        let outcomes = vec![
+            (CargoPackage::with("my-app", "1.0.0"), None),
+            analysed("tokio", FactorStatus::Verified),
+            analysed("mio", FactorStatus::Missing),
+            analysed("libc", FactorStatus::Verified),
+            analysed("orphan", FactorStatus::Verified),
+        ];
+
+        let unverifiable = vec![from_git("acme-utils"), from_git("acme-orphan")];
+
+        let depths = HashMap::from([
+            (CargoPackage::with("my-app", "1.0.0"), 0),
+            (CargoPackage::with("tokio", "1.0.0"), 1),
+            (CargoPackage::with("mio", "1.0.0"), 2),
+            (CargoPackage::with("libc", "1.0.0"), 3),
+            (CargoPackage::with("acme-utils", "0.1.0"), 1),
+        ]);
+
+        let tiers = tier_statistics(&outcomes, &unverifiable, &depths);
+
+        let expected = BTreeMap::from([
+            (
+                DependencyTier::Direct,
+                TierStatistics {
+                    total: 3,
+                    verified: BTreeMap::from([(VeracityFactor::TrustedPublishing, 1)]),
+                },
+            ),
+            (
+                DependencyTier::Transitive,
+                TierStatistics {
+                    total: 2,
+                    verified: BTreeMap::from([(VeracityFactor::TrustedPublishing, 1)]),
+                },
+            ),
+        ]);
+
+        assertor::assert_that!(tiers).is_equal_to(expected);
+    }
+
+    #[test]
+    fn should_skip_tiers_without_dependency_graph() {
+        let outcomes = vec![analysed("tokio", FactorStatus::Verified)];
+        let unverifiable = vec![from_git("acme-utils")];
+
+        let tiers = tier_statistics(&outcomes, &unverifiable, &HashMap::new());
+
+        assertor::assert_that!(tiers.is_empty()).is_true();
+    }
+
+    #[test]
+    fn should_split_results_across_members_sharing_dependencies() {
+        let first_member = CargoPackage::with("my-app", "1.0.0");
+        let second_member = CargoPackage::with("my-cli", "1.0.0");
+        let serde = CargoPackage::with("serde", "1.0.0");
+        let tokio = CargoPackage::with("tokio", "1.0.0");
+        let clap = CargoPackage::with("clap", "1.0.0");
+        let acme_utils = from_git("acme-utils").cargo_package;
+
+        let graph = DependencyGraph::new(
+            vec![first_member.clone(), second_member.clone()],
+            HashMap::from([
+                (first_member.clone(), vec![serde.clone(), tokio.clone()]),
+                (second_member.clone(), vec![clap.clone(), acme_utils.clone()]),
+                (clap.clone(), vec![serde.clone()]),
+                (serde, vec![]),
+                (tokio, vec![]),
+                (acme_utils, vec![]),
+            ]),
+        );
+
+        // Analysed packages carry checksums from the lockfile, unlike graph nodes
+        let outcomes = vec![
+            analysed("serde", FactorStatus::Verified),
+            analysed("tokio", FactorStatus::Missing),
+            analysed("clap", FactorStatus::Verified),
+        ]
+        .into_iter()
+        .map(|(package, checks)| {
+            let checksum = Some(format!("{}-checksum", package.name));
+            (
+                CargoPackage::with_checksum(package.name, package.version, checksum),
+                checks,
+            )
+        })
+        .collect::<Vec<_>>();
+
+        let factors = vec![VeracityFactor::TrustedPublishing];
+
+        let rollup = AnalysisResults {
+            statistics: aggregate_statistics(&factors, &outcomes),
+            outcomes,
+            unverifiable: vec![from_git("acme-utils")],
+            depths: graph.depths(),
+        };
+
+        let first = member_results(&factors, &rollup, &graph.depths_from(&[first_member]));
+        let second = member_results(&factors, &rollup, &graph.depths_from(&[second_member]));
+
+        let names = |results: &AnalysisResults| {
+            results
+                .outcomes
+                .iter()
+                .map(|(package, _)| package.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assertor::assert_that!(names(&first)).is_equal_to(vec!["serde".to_string(), "tokio".to_string()]);
+        assertor::assert_that!(names(&second)).is_equal_to(vec!["serde".to_string(), "clap".to_string()]);
+
+        assertor::assert_that!(first.statistics.total).is_equal_to(2);
+        assertor::assert_that!(first.statistics.verified[&VeracityFactor::TrustedPublishing]).is_equal_to(1);
+        assertor::assert_that!(first.unverifiable.is_empty()).is_true();
+
+        assertor::assert_that!(second.statistics.total).is_equal_to(3);
+        assertor::assert_that!(second.statistics.unverifiable).is_equal_to(1);
+        assertor::assert_that!(second.statistics.verified[&VeracityFactor::TrustedPublishing]).is_equal_to(2);
+
+        let shared = &second.outcomes[0].0;
+        assertor::assert_that!(second.tier_of(shared)).is_equal_to(Some(DependencyTier::Transitive));
+        assertor::assert_that!(first.tier_of(&first.outcomes[0].0)).is_equal_to(Some(DependencyTier::Direct));
+    }
+}
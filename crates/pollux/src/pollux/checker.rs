@@ -3,15 +3,84 @@
 
 use crate::core::analysers::combined::VeracityChecksAnalyser;
 use crate::core::interfaces::CrateVeracityAnalysis;
-use crate::core::models::{CargoPackage, CrateVeracityChecks};
+use crate::core::models::{
+    CargoPackage, CrateReference, CrateVeracityChecks, HistoryOutcome, PublishedVersion, VeracityFactor,
+};
+use crate::infra::networking::crates::CrateVersionResolver;
+use anyhow::bail;
+use semver::VersionReq;
 
 pub struct PolluxChecker {
+    version_resolver: CrateVersionResolver,
     veracity_analyser: VeracityChecksAnalyser,
 }
 
 impl PolluxChecker {
-    pub fn new(veracity_analyser: VeracityChecksAnalyser) -> Self {
-        Self { veracity_analyser }
+    pub fn new(version_resolver: CrateVersionResolver, veracity_analyser: VeracityChecksAnalyser) -> Self {
+        Self {
+            version_resolver,
+            veracity_analyser,
+        }
+    }
+
+    pub async fn resolve_version(&self, crate_reference: CrateReference) -> anyhow::Result<CargoPackage> {
+        self.version_resolver.resolve(crate_reference).await
+    }
+
+    pub async fn check_history(
+        &self,
+        crate_name: &str,
+        requirement: Option<&VersionReq>,
+    ) -> anyhow::Result<Vec<HistoryOutcome>> {
+        let published_versions = self
+            .version_resolver
+            .published_versions(crate_name, requirement)
+            .await?;
+
+        if published_versions.is_empty() {
+            bail!("pollux.history : no published versions of {} to check", crate_name);
+        }
+
+        let cargo_packages = published_versions
+            .iter()
+            .map(|published| published.cargo_package.clone())
+            .collect::<Vec<_>>();
+
+        self.veracity_analyser.prefetch_rebuilds(&cargo_packages).await;
+
+        let mut outcomes = vec![];
+
+        for published in published_versions {
+            let checks = self.check_published_version(&published).await.ok();
+            outcomes.push((published, checks));
+        }
+
+        Ok(outcomes)
+    }
+
+    // Trusted publishing comes along with the versions history, hence only rebuilds need lookups
+    async fn check_published_version(&self, published: &PublishedVersion) -> anyhow::Result<CrateVeracityChecks> {
+        log::info!(
+            "[pollux.checker] starting evaluation for version {}",
+            published.cargo_package
+        );
+
+        let mut checks = CrateVeracityChecks::default();
+        checks.record(VeracityFactor::TrustedPublishing, published.trusted_publishing.clone());
+
+        if let Some(reproducibility) = self
+            .veracity_analyser
+            .execute_factor(VeracityFactor::ReproducibleBuilds, &published.cargo_package)
+            .await?
+        {
+            checks.record(VeracityFactor::ReproducibleBuilds, reproducibility);
+        }
+
+        log::info!(
+            "[pollux.checker] finished evaluation for version {}",
+            published.cargo_package
+        );
+        Ok(checks)
     }
 
     pub async fn check_package(&self, cargo_package: &CargoPackage) -> anyhow::Result<CrateVeracityChecks> {
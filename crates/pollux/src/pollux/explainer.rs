@@ -0,0 +1,80 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::analysers::combined::VeracityChecksAnalyser;
+use crate::core::interfaces::CrateVeracityAnalysis;
+use crate::core::models::{CargoPackage, CrateVeracityChecks, DependencyHop, DependencyPath};
+use crate::infra::networking::crates::resolvers::DependenciesResolver;
+use anyhow::bail;
+use camino::Utf8PathBuf;
+use std::collections::HashMap;
+
+pub struct PolluxExplainer {
+    dependencies_resolver: DependenciesResolver,
+    veracity_analyser: VeracityChecksAnalyser,
+}
+
+impl PolluxExplainer {
+    pub fn new(dependencies_resolver: DependenciesResolver, veracity_analyser: VeracityChecksAnalyser) -> Self {
+        Self {
+            dependencies_resolver,
+            veracity_analyser,
+        }
+    }
+
+    pub async fn explain_dependency(
+        &self,
+        project_path: Utf8PathBuf,
+        crate_name: &str,
+        crate_version: Option<&str>,
+    ) -> anyhow::Result<Vec<DependencyPath>> {
+        let dependencies = self
+            .dependencies_resolver
+            .resolve_for_local_project(project_path)
+            .await?;
+
+        let routes = dependencies.graph.routes_to(crate_name, crate_version);
+
+        if routes.is_empty() {
+            bail!("pollux.why : {} not found in dependency graph", crate_name);
+        }
+
+        let mut evaluated = HashMap::<CargoPackage, Option<CrateVeracityChecks>>::new();
+        let mut explained = vec![];
+
+        for route in routes {
+            let mut hops = vec![];
+
+            for cargo_package in route.shortest_path {
+                let local = dependencies.graph.is_local(&cargo_package);
+                let unverifiable_source = dependencies
+                    .unverifiable
+                    .iter()
+                    .find(|unverifiable| unverifiable.cargo_package == cargo_package)
+                    .map(|unverifiable| unverifiable.source.clone());
+
+                if !local && unverifiable_source.is_none() && !evaluated.contains_key(&cargo_package) {
+                    log::info!("[pollux.explainer] evaluating hop {}", cargo_package);
+                    let checks = self.veracity_analyser.execute(&cargo_package).await.ok();
+                    evaluated.insert(cargo_package.clone(), checks);
+                }
+
+                let checks = evaluated.get(&cargo_package).cloned().flatten();
+
+                hops.push(DependencyHop {
+                    cargo_package,
+                    local,
+                    unverifiable_source,
+                    checks,
+                });
+            }
+
+            explained.push(DependencyPath {
+                hops,
+                total_paths: route.total_paths,
+            });
+        }
+
+        Ok(explained)
+    }
+}
@@ -3,7 +3,7 @@
 
 use crate::core::analysers::combined::VeracityChecksAnalyser;
 use crate::core::interfaces::CrateVeracityAnalysis;
-use crate::core::models::{EcosystemInquiringResults, InquireCoverage, InquiringOutcome};
+use crate::core::models::{EcosystemInquiringResults, InquireCoverage, InquiringOutcome, VeracityFactor};
 use crate::infra::networking::crates::PopularCratesFetcher;
 
 pub struct PolluxInquirer {
@@ -24,6 +24,7 @@ impl PolluxInquirer {
         coverage: InquireCoverage,
     ) -> anyhow::Result<EcosystemInquiringResults> {
         let popular_packages = self.popular_crates_fetcher.get_most_popular_crates(coverage).await?;
+        self.veracity_analyser.prefetch_rebuilds(&popular_packages).await;
 
         let mut inquired_packages = vec![];
         let mut with_trusted_publishing = 0;
@@ -32,21 +33,12 @@ impl PolluxInquirer {
         for cargo_package in popular_packages {
             let checks = self.veracity_analyser.execute(&cargo_package).await?;
 
-            match (&checks.trusted_publishing_evidence, &checks.reproducibility_evidence) {
-                (Some(_), Some(_)) => {
-                    with_trusted_publishing += 1;
-                    with_reproducibility += 1;
-                },
-                (Some(_), None) => {
-                    with_trusted_publishing += 1;
-                },
-                (None, Some(_)) => {
-                    with_reproducibility += 1;
-                },
+            if checks.is_verified(VeracityFactor::TrustedPublishing) {
+                with_trusted_publishing += 1;
+            }
 
-                (None, None) => {
-                    log::info!("[pollux.inquirer] not counting crate {}", cargo_package);
-                },
+            if checks.is_verified(VeracityFactor::ReproducibleBuilds) {
+                with_reproducibility += 1;
             }
 
             inquired_packages.push(InquiringOutcome { cargo_package, checks });
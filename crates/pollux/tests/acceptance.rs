@@ -75,6 +75,7 @@ fn should_check_standalone_package_purl() {
         .stdout(contains(
             "trusted publishing evidence : https://github.com/elastio/bon/actions/runs/17402178810",
         ))
+        .stdout(contains("commit : bbd8b099ea52bf4de18051d012c8113cf0dca23a"))
         .stdout(contains("reproducibility evidence : not found"));
 }
 